@@ -0,0 +1,46 @@
+//! Benchmarks parsing and solving of every registered part against its `inputs/real.txt`
+//!
+//! Only the parts with an answer recorded for the input are benchmarked,
+//! so that unfinished (or hopelessly slow) solutions don't get in the way.
+//! Use the usual Criterion filters to narrow it down, e.g. `cargo bench -- d14/p2`
+
+use std::hint::black_box;
+
+use aoc::{inputs_dir, registry};
+use common::answers::Answers;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+const INPUT: &str = "real.txt";
+
+fn days(c: &mut Criterion) {
+    for day in registry::days() {
+        let inputs = inputs_dir(day);
+        let Ok(file) = std::fs::read_to_string(inputs.join(INPUT)) else {
+            continue;
+        };
+        let answers = Answers::load(&inputs).unwrap_or_default();
+
+        let mut group = c.benchmark_group(format!("d{day:02}"));
+        for &solution in registry::day(day).unwrap_or_default() {
+            let part = solution.part();
+            if answers.expected(INPUT, part).is_none() {
+                continue;
+            }
+
+            group.bench_function(format!("p{part}/parse"), |b| {
+                b.iter(|| solution.parse(black_box(&file)).expect("failed to parse"));
+            });
+            group.bench_function(format!("p{part}/solve"), |b| {
+                b.iter_batched(
+                    || solution.parse(&file).expect("failed to parse"),
+                    |parsed| parsed.solve().expect("failed to solve"),
+                    BatchSize::SmallInput,
+                );
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! Benchmarks every registered part against randomly generated inputs of growing size
+//!
+//! Unlike the `days` benchmarks, these don't need any checked-in inputs,
+//! and show how the solutions scale. E.g. `cargo bench --bench generated -- d10`
+
+use std::hint::black_box;
+
+use aoc::registry;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const SEED: u64 = 2023;
+const SIZES: [usize; 3] = [10, 30, 100];
+
+fn generated(c: &mut Criterion) {
+    for day in registry::days() {
+        let Some(generator) = registry::generator(day) else {
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("d{day:02}/generated"));
+        for size in SIZES {
+            let input = generator(&mut common::gen::rng(SEED), size);
+            for &solution in registry::day(day).unwrap_or_default() {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("p{}", solution.part()), size),
+                    &input,
+                    |b, input| {
+                        b.iter(|| solution.solve(black_box(input)).expect("failed to solve"));
+                    },
+                );
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, generated);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+//! Benchmarks the parts which the `parallel` feature spreads over the cores, on a single thread
+//! and on all of them, against their `inputs/real.txt`
+//!
+//! Like in the `days` benchmarks, only the parts with an answer recorded for the input are
+//! benchmarked.
+//!
+//! Needs the feature: `cargo bench --bench parallel --features parallel`
+
+use std::hint::black_box;
+
+use aoc::{inputs_dir, registry};
+use common::{answers::Answers, Part};
+use criterion::{criterion_group, criterion_main, Criterion};
+use rayon::ThreadPoolBuilder;
+
+const INPUT: &str = "real.txt";
+
+const PARALLEL: &[(u8, Part)] = &[
+    (8, Part::Two),
+    (11, Part::One),
+    (11, Part::Two),
+    (12, Part::Two),
+    (16, Part::Two),
+    (19, Part::One),
+];
+
+fn parallel(c: &mut Criterion) {
+    let mut threads = vec![1, rayon::current_num_threads()];
+    // with a single core, there's nothing to compare
+    threads.dedup();
+    let pools: Vec<_> = threads
+        .into_iter()
+        .map(|n_threads| {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(n_threads)
+                .build()
+                .expect("failed to build a thread pool");
+            (n_threads, pool)
+        })
+        .collect();
+
+    for &(day, part) in PARALLEL {
+        let inputs = inputs_dir(day);
+        let Ok(file) = std::fs::read_to_string(inputs.join(INPUT)) else {
+            continue;
+        };
+        let answers = Answers::load(&inputs).unwrap_or_default();
+        if answers.expected(INPUT, part).is_none() {
+            continue;
+        }
+        let solution = registry::day(day)
+            .unwrap_or_default()
+            .iter()
+            .find(|s| s.part() == part)
+            .expect("the part is registered");
+
+        let mut group = c.benchmark_group(format!("d{day:02}/p{part}"));
+        for (n_threads, pool) in &pools {
+            group.bench_function(format!("threads/{n_threads}"), |b| {
+                b.iter(|| {
+                    pool.install(|| solution.solve(black_box(&file)).expect("failed to solve"))
+                });
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, parallel);
+criterion_main!(benches);
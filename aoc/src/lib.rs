@@ -0,0 +1,36 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+pub mod registry;
+pub mod render;
+
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+/// The `inputs` directory of the day's crate
+pub fn inputs_dir(day: u8) -> PathBuf {
+    workspace_root().join(format!("d{day:02}")).join("inputs")
+}
+
+/// The first, by name, of the `example*` inputs of the day
+pub fn first_example(day: u8) -> anyhow::Result<PathBuf> {
+    let dir = inputs_dir(day);
+    let mut examples = vec![];
+    for entry in std::fs::read_dir(&dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with("example"))
+        {
+            examples.push(path);
+        }
+    }
+    examples
+        .into_iter()
+        .min()
+        .with_context(|| format!("no example input in {}", dir.display()))
+}
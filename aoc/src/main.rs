@@ -0,0 +1,165 @@
+use core::str::FromStr;
+use std::{path::PathBuf, process::ExitCode};
+
+use anyhow::{bail, ensure, Context};
+use aoc::render::Format;
+use clap::{Parser, Subcommand};
+use common::Part;
+use run::DumpFormat;
+use tracing_subscriber::EnvFilter;
+
+mod picture;
+mod run;
+mod tui;
+mod verify;
+
+#[derive(Parser)]
+#[command(
+    version,
+    about = "Run Advent of Code 2023 solutions",
+    after_help = format!(
+        "Set `{LOG_ENV}` to a filter like `info` or `d17=trace` to print what the solutions are doing to stderr"
+    )
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a solution, printing the answer and the time it took
+    Run {
+        /// Day to run, or `all` to run every registered solution
+        day: Day,
+        /// Part to run (both if not specified)
+        #[arg(value_parser = parse_part)]
+        part: Option<Part>,
+        /// File to read the input from, `-` for stdin, or a directory to run against every
+        /// input in it [default: `inputs/real.txt` of the day's crate]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Write the answers to the `answers.toml` next to the input
+        #[arg(long)]
+        record: bool,
+        /// Print the parsed inputs instead of solving them
+        /// (needs the runner to be built with the `serde` feature)
+        #[arg(long, value_enum, value_name = "FORMAT", conflicts_with = "record")]
+        dump_parsed: Option<DumpFormat>,
+    },
+    /// Draw a picture of what happens with an input
+    Render {
+        /// Day to draw, one of those with a renderer
+        #[arg(value_parser = parse_single_day)]
+        day: u8,
+        /// File to read the input from, `-` for stdin
+        /// [default: `inputs/real.txt` of the day's crate]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[arg(short, long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// File to write the picture to [default: stdout]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Step through what happens with an input, going back and forth between its states
+    Step {
+        /// Day to step through, one of those with a stepper
+        #[arg(value_parser = parse_single_day)]
+        day: u8,
+        /// Part to step through
+        #[arg(value_parser = parse_part, default_value = "1")]
+        part: Part,
+        /// File to read the input from, `-` for stdin
+        /// [default: `inputs/real.txt` of the day's crate]
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Check the solutions against the answers recorded in `answers.toml`s
+    Verify {
+        /// Day to verify, or `all` to verify every registered solution
+        #[arg(default_value = "all")]
+        day: Day,
+    },
+}
+
+/// The environment variable with the filter for the traces to print
+const LOG_ENV: &str = "AOC_LOG";
+
+#[derive(Clone, Copy)]
+enum Day {
+    All,
+    One(u8),
+}
+
+impl FromStr for Day {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        let day = s.parse().with_context(|| format!("invalid day: {s}"))?;
+        ensure!((1..=25).contains(&day), "day out of range: {day}");
+        Ok(Self::One(day))
+    }
+}
+
+fn parse_single_day(s: &str) -> anyhow::Result<u8> {
+    match s.parse()? {
+        Day::One(day) => Ok(day),
+        Day::All => bail!("a single day is needed"),
+    }
+}
+
+fn parse_part(s: &str) -> anyhow::Result<Part> {
+    let part: u8 = s.parse().with_context(|| format!("invalid part: {s}"))?;
+    Part::try_from(part)
+}
+
+/// Prints the traces `LOG_ENV` lets through to stderr, if it's set
+fn init_tracing() -> anyhow::Result<()> {
+    let Ok(filter) = std::env::var(LOG_ENV) else {
+        return Ok(());
+    };
+    let filter =
+        EnvFilter::try_new(&filter).with_context(|| format!("invalid `{LOG_ENV}`: {filter}"))?;
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+    Ok(())
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    let cli = Cli::parse();
+    init_tracing()?;
+
+    let all_ok = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            record,
+            dump_parsed,
+        } => match (dump_parsed, input.as_deref()) {
+            (Some(format), input) => run::dump(day, part, input, format)?,
+            (None, Some(dir)) if dir.is_dir() => run::batch(day, part, dir, record)?,
+            (None, input) => run::run(day, part, input, record)?,
+        },
+        Command::Render {
+            day,
+            input,
+            format,
+            output,
+        } => picture::render(day, input.as_deref(), format, output.as_deref())?,
+        Command::Step { day, part, input } => tui::step(day, part, input.as_deref())?,
+        Command::Verify { day } => verify::verify(day)?,
+    };
+
+    Ok(if all_ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,35 @@
+use std::{io::Write, path::Path};
+
+use anyhow::Context;
+use aoc::{
+    inputs_dir, registry,
+    render::{self, Format},
+};
+
+use crate::run::read_input;
+
+pub(crate) fn render(
+    day: u8,
+    input: Option<&Path>,
+    format: Format,
+    output: Option<&Path>,
+) -> anyhow::Result<bool> {
+    let renderer =
+        registry::renderer(day).with_context(|| format!("no renderer registered for day {day}"))?;
+
+    let path = input.map_or_else(|| inputs_dir(day).join("real.txt"), Path::to_path_buf);
+    let file = read_input(&path)?;
+    let bytes = match (format, registry::animator(day)) {
+        (Format::Gif, Some(animate)) => render::gif(|on_frame| animate(&file, on_frame))?,
+        _ => format.encode(&renderer(&file)?)?,
+    };
+
+    match output {
+        Some(output) => std::fs::write(output, bytes)
+            .with_context(|| format!("failed to write the picture to {}", output.display()))?,
+        None => std::io::stdout()
+            .write_all(&bytes)
+            .context("failed to write the picture to stdout")?,
+    }
+    Ok(true)
+}
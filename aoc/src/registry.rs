@@ -0,0 +1,99 @@
+use common::{gen::Generator, Part, Solution};
+use grid::picture::{Picture, Simulation};
+
+/// Draws a picture of what happens with the input
+pub type Renderer = fn(&str) -> anyhow::Result<Picture>;
+
+/// Draws the frames of an animation of what happens with the input, handing them over one by one
+pub type Animator = fn(&str, &mut dyn FnMut(&Picture)) -> anyhow::Result<()>;
+
+/// Sets up a simulation of what happens with the input, to step through
+pub type Stepper = fn(&str) -> anyhow::Result<Box<dyn Simulation>>;
+
+const DAYS: &[(&[&dyn Solution], Generator)] = &[
+    (d01::SOLUTIONS, d01::gen::input),
+    (d02::SOLUTIONS, d02::gen::input),
+    (d03::SOLUTIONS, d03::gen::input),
+    (d04::SOLUTIONS, d04::gen::input),
+    (d05::SOLUTIONS, d05::gen::input),
+    (d06::SOLUTIONS, d06::gen::input),
+    (d07::SOLUTIONS, d07::gen::input),
+    (d08::SOLUTIONS, d08::gen::input),
+    (d09::SOLUTIONS, d09::gen::input),
+    (d10::SOLUTIONS, d10::gen::input),
+    (d11::SOLUTIONS, d11::gen::input),
+    (d12::SOLUTIONS, d12::gen::input),
+    (d13::SOLUTIONS, d13::gen::input),
+    (d14::SOLUTIONS, d14::gen::input),
+    (d15::SOLUTIONS, d15::gen::input),
+    (d16::SOLUTIONS, d16::gen::input),
+    (d17::SOLUTIONS, d17::gen::input),
+    (d18::SOLUTIONS, d18::gen::input),
+    (d19::SOLUTIONS, d19::gen::input),
+    (d20::SOLUTIONS, d20::gen::input),
+    (d21::SOLUTIONS, d21::gen::input),
+    (d22::SOLUTIONS, d22::gen::input),
+];
+
+/// All the registered solutions, ordered by day and then part
+pub fn solutions() -> impl Iterator<Item = &'static dyn Solution> {
+    DAYS.iter().flat_map(|(day, _)| day.iter().copied())
+}
+
+/// The solutions registered for `day`
+pub fn day(day: u8) -> Option<&'static [&'static dyn Solution]> {
+    DAYS.iter()
+        .map(|&(solutions, _)| solutions)
+        .find(|solutions| solutions.first().is_some_and(|s| s.day() == day))
+}
+
+/// The generator of random inputs for `day`
+pub fn generator(day: u8) -> Option<Generator> {
+    DAYS.iter()
+        .find(|(solutions, _)| solutions.first().is_some_and(|s| s.day() == day))
+        .map(|&(_, generator)| generator)
+}
+
+/// The numbers of all the days with registered solutions
+pub fn days() -> impl Iterator<Item = u8> {
+    DAYS.iter()
+        .filter_map(|(solutions, _)| Some(solutions.first()?.day()))
+}
+
+/// The renderer of the pictures for `day`, if it has one
+pub fn renderer(day: u8) -> Option<Renderer> {
+    let renderer: Renderer = match day {
+        10 => d10::render::render,
+        11 => d11::render::render,
+        14 => d14::render::render,
+        16 => d16::render::render,
+        17 => d17::render::render,
+        _ => return None,
+    };
+    Some(renderer)
+}
+
+/// The animator for `day`, if it has one
+pub fn animator(day: u8) -> Option<Animator> {
+    let animator: Animator = match day {
+        14 => d14::render::animate,
+        16 => d16::render::animate,
+        17 => d17::render::animate,
+        _ => return None,
+    };
+    Some(animator)
+}
+
+/// The stepper for `part` of `day`, if it has one
+///
+/// Days whose parts go through the same steps have the same stepper for both
+pub fn stepper(day: u8, part: Part) -> Option<Stepper> {
+    let stepper: Stepper = match (day, part) {
+        (14, _) => d14::render::simulate,
+        (16, _) => d16::render::simulate,
+        (17, Part::One) => d17::render::simulate_p1,
+        (17, Part::Two) => d17::render::simulate_p2,
+        _ => return None,
+    };
+    Some(stepper)
+}
@@ -0,0 +1,378 @@
+//! Turning the [`Picture`]s the days draw into coloured terminal output, SVG or PNG images,
+//! or a sequence of them into an animated GIF
+
+use core::fmt::Write;
+use std::collections::HashMap;
+
+use anyhow::ensure;
+use clap::ValueEnum;
+use grid::picture::{Picture, Rgb};
+
+/// The size of a tile in an SVG, in pixels
+const SVG_TILE: usize = 16;
+/// The size of a tile in a PNG, in pixels
+const PNG_TILE: usize = 8;
+/// The size of a tile in a GIF, in pixels, smaller than in a PNG to keep the animations small
+const GIF_TILE: usize = 4;
+/// How long each frame of a GIF is shown for, in hundredths of a second
+const GIF_DELAY: u16 = 5;
+/// How long the last frame of a GIF is shown for before it starts over
+const GIF_LAST_DELAY: u16 = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Text coloured with ANSI escape codes
+    Text,
+    Svg,
+    Png,
+    /// An animation, for the days which have one, and a single frame for the rest
+    Gif,
+}
+
+impl Format {
+    pub fn encode(self, picture: &Picture) -> anyhow::Result<Vec<u8>> {
+        Ok(match self {
+            Self::Text => text(picture).into_bytes(),
+            Self::Svg => svg(picture).into_bytes(),
+            Self::Png => png(picture)?,
+            Self::Gif => gif(|on_frame| {
+                on_frame(picture);
+                Ok(())
+            })?,
+        })
+    }
+}
+
+/// The picture as glyphs in 24-bit colour, for terminals which support it
+pub fn text(picture: &Picture) -> String {
+    let mut s = String::new();
+    for row in picture.rows() {
+        let mut colours = None;
+        for tile in row {
+            if colours != Some((tile.fg, tile.bg)) {
+                let (Rgb(fr, fg, fb), Rgb(br, bg, bb)) = (tile.fg, tile.bg);
+                let _ = write!(s, "\x1b[38;2;{fr};{fg};{fb};48;2;{br};{bg};{bb}m");
+                colours = Some((tile.fg, tile.bg));
+            }
+            s.push(tile.glyph);
+        }
+        s.push_str("\x1b[0m\n");
+    }
+    s
+}
+
+fn escape_xml(c: char) -> String {
+    match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        c => c.to_string(),
+    }
+}
+
+/// The picture as a square with the tile's glyph on it for each tile
+pub fn svg(picture: &Picture) -> String {
+    let (width, height) = (picture.width() * SVG_TILE, picture.height() * SVG_TILE);
+    let mut s = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{}\" \
+         text-anchor=\"middle\" dominant-baseline=\"central\">\n",
+        SVG_TILE - 2
+    );
+    for (pos, tile) in picture.iter() {
+        let (x, y) = (pos.x() * SVG_TILE, pos.y() * SVG_TILE);
+        let _ = writeln!(
+            s,
+            "<rect x=\"{x}\" y=\"{y}\" width=\"{SVG_TILE}\" height=\"{SVG_TILE}\" fill=\"{}\"/>",
+            tile.bg.hex()
+        );
+        if !tile.is_blank() {
+            let (cx, cy) = (x + SVG_TILE / 2, y + SVG_TILE / 2);
+            let _ = writeln!(
+                s,
+                "<text x=\"{cx}\" y=\"{cy}\" fill=\"{}\">{}</text>",
+                tile.fg.hex(),
+                escape_xml(tile.glyph)
+            );
+        }
+    }
+    s.push_str("</svg>\n");
+    s
+}
+
+/// The pixels of the picture, row by row, with a `size`×`size` square of the background
+/// for each tile, and a smaller square of the foreground in the middle of it
+/// standing in for the glyph, since there are no fonts to draw it with
+fn pixels(picture: &Picture, size: usize) -> Vec<Rgb> {
+    let margin = size / 4;
+    let inner = margin..size - margin;
+    let mut pixels = Vec::with_capacity(picture.width() * picture.height() * size * size);
+    for row in picture.rows() {
+        for y in 0..size {
+            for tile in row {
+                for x in 0..size {
+                    let is_glyph = inner.contains(&x) && inner.contains(&y) && !tile.is_blank();
+                    pixels.push(if is_glyph { tile.fg } else { tile.bg });
+                }
+            }
+        }
+    }
+    pixels
+}
+
+pub fn png(picture: &Picture) -> anyhow::Result<Vec<u8>> {
+    let (width, height) = (picture.width() * PNG_TILE, picture.height() * PNG_TILE);
+    let data: Vec<u8> = pixels(picture, PNG_TILE)
+        .into_iter()
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+
+    let mut png = vec![];
+    let mut encoder = png::Encoder::new(&mut png, u32::try_from(width)?, u32::try_from(height)?);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&data)?;
+    writer.finish()?;
+    Ok(png)
+}
+
+/// A frame of the picture with a palette of its own, as it has few enough colours
+/// not to need quantizing
+fn gif_frame(picture: &Picture) -> anyhow::Result<gif::Frame<'static>> {
+    let mut palette = HashMap::new();
+    let mut indices = Vec::with_capacity(picture.width() * picture.height() * GIF_TILE * GIF_TILE);
+    for colour in pixels(picture, GIF_TILE) {
+        let n = palette.len();
+        let i = *palette.entry(colour).or_insert(n);
+        ensure!(
+            i < 256,
+            "the frame has more than the 256 colours a GIF frame can have"
+        );
+        indices.push(i as u8);
+    }
+    let mut colours = vec![Rgb::BLACK; palette.len()];
+    for (colour, i) in palette {
+        colours[i] = colour;
+    }
+
+    Ok(gif::Frame {
+        width: u16::try_from(picture.width() * GIF_TILE)?,
+        height: u16::try_from(picture.height() * GIF_TILE)?,
+        delay: GIF_DELAY,
+        palette: Some(
+            colours
+                .into_iter()
+                .flat_map(|Rgb(r, g, b)| [r, g, b])
+                .collect(),
+        ),
+        buffer: indices.into(),
+        ..gif::Frame::default()
+    })
+}
+
+/// Encodes the frames of a GIF as they come
+#[derive(Default)]
+struct GifWriter {
+    encoder: Option<gif::Encoder<Vec<u8>>>,
+    /// The size of the frames, in tiles
+    size: (usize, usize),
+    /// The latest frame, held back until it's known whether it's the last one
+    pending: Option<gif::Frame<'static>>,
+}
+
+impl GifWriter {
+    fn push(&mut self, picture: &Picture) -> anyhow::Result<()> {
+        let size = (picture.width(), picture.height());
+        let encoder = if let Some(encoder) = &mut self.encoder {
+            ensure!(size == self.size, "the frames are not all of the same size");
+            encoder
+        } else {
+            let (width, height) = (size.0 * GIF_TILE, size.1 * GIF_TILE);
+            // no global palette, as each frame has its own
+            let mut encoder =
+                gif::Encoder::new(vec![], u16::try_from(width)?, u16::try_from(height)?, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.size = size;
+            self.encoder.insert(encoder)
+        };
+        if let Some(previous) = self.pending.replace(gif_frame(picture)?) {
+            encoder.write_frame(&previous)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> anyhow::Result<Vec<u8>> {
+        let (Some(mut encoder), Some(mut last)) = (self.encoder, self.pending) else {
+            anyhow::bail!("there are no frames to animate");
+        };
+        last.delay = GIF_LAST_DELAY;
+        encoder.write_frame(&last)?;
+        Ok(encoder.into_inner()?)
+    }
+}
+
+/// The frames `animate` draws as an animation which loops forever, pausing on the last frame
+///
+/// The frames are encoded as they're drawn, so only one or two of them are kept at a time.
+/// They all have to be of the same size, and have no more than 256 colours each
+pub fn gif(
+    animate: impl FnOnce(&mut dyn FnMut(&Picture)) -> anyhow::Result<()>,
+) -> anyhow::Result<Vec<u8>> {
+    let mut writer = GifWriter::default();
+    // the first error stops the encoding, but the animation goes on until it's done
+    let mut encoded = Ok(());
+    animate(&mut |picture| {
+        if encoded.is_ok() {
+            encoded = writer.push(picture);
+        }
+    })?;
+    encoded?;
+    writer.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use grid::picture::Tile;
+
+    use super::*;
+    use crate::{first_example, registry};
+    use common::Part;
+
+    fn picture() -> Picture {
+        let galaxy = Tile::new('#', Rgb::WHITE);
+        let space = Tile::new('.', Rgb::GREY);
+        Picture::from_rows([vec![galaxy, space], vec![space, galaxy.on(Rgb::BLUE)]]).unwrap()
+    }
+
+    #[test]
+    fn text_changes_colours_only_when_needed() {
+        let text = text(&picture());
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(text.matches("\x1b[38;2;").count(), 4);
+        assert!(text.ends_with("#\x1b[0m\n"));
+    }
+
+    #[test]
+    fn svg_has_a_square_for_each_tile() {
+        let svg = svg(&picture());
+        assert_eq!(svg.matches("<rect").count(), 4);
+        // `.` is blank
+        assert_eq!(svg.matches("<text").count(), 2);
+    }
+
+    #[test]
+    fn png_has_a_square_for_each_tile() {
+        let png = png(&picture()).unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (16, 16));
+    }
+
+    fn gif_of(frames: &[Picture]) -> anyhow::Result<Vec<u8>> {
+        gif(|on_frame| {
+            frames.iter().for_each(on_frame);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn gif_has_all_the_frames() {
+        let frames = [picture(), picture().map(|tile| tile.on(Rgb::RED))];
+        let gif = gif_of(&frames).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut n_frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height), (8, 8));
+            n_frames += 1;
+        }
+        assert_eq!(n_frames, 2);
+    }
+
+    #[test]
+    fn gif_frames_have_the_same_size() {
+        let other = Picture::from_rows([vec![Tile::new('#', Rgb::WHITE)]]).unwrap();
+        assert!(gif_of(&[picture(), other]).is_err());
+    }
+
+    #[test]
+    fn gif_frames_have_palettes_of_their_own() {
+        // 200 greys, and then 200 reds, too many colours for a single palette
+        let frames: Vec<_> = [Rgb::WHITE, Rgb::RED]
+            .into_iter()
+            .map(|colour| {
+                let row = (0..200)
+                    .map(|i| {
+                        Tile::new(' ', Rgb::BLACK).on(Rgb::BLACK.mix(colour, f64::from(i) / 200.0))
+                    })
+                    .collect();
+                Picture::from_rows([row]).unwrap()
+            })
+            .collect();
+        let gif = gif_of(&frames).unwrap();
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut delays = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            delays.push(frame.delay);
+        }
+        assert_eq!(delays, [GIF_DELAY, GIF_LAST_DELAY]);
+
+        let row = (0..300)
+            .map(|i| Tile::new(' ', Rgb::BLACK).on(Rgb(0, (i / 256) as u8, (i % 256) as u8)))
+            .collect();
+        assert!(gif_of(&[Picture::from_rows([row]).unwrap()]).is_err());
+    }
+
+    /// The first of the example inputs of `day`
+    fn example(day: u8) -> String {
+        std::fs::read_to_string(first_example(day).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn renderers_draw_the_examples() {
+        for day in registry::days() {
+            let Some(render) = registry::renderer(day) else {
+                continue;
+            };
+            let file = example(day);
+            let picture = render(&file).unwrap_or_else(|e| panic!("day {day}: {e:#}"));
+            assert_eq!(picture.height(), file.lines().count(), "day {day}");
+        }
+    }
+
+    #[test]
+    fn animators_animate_the_examples() {
+        for day in registry::days() {
+            let Some(animate) = registry::animator(day) else {
+                continue;
+            };
+            let file = example(day);
+            let mut n_frames = 0;
+            animate(&file, &mut |_| n_frames += 1).unwrap_or_else(|e| panic!("day {day}: {e:#}"));
+            assert!(n_frames > 1, "day {day}");
+            gif(|on_frame| animate(&file, on_frame)).unwrap_or_else(|e| panic!("day {day}: {e:#}"));
+        }
+    }
+
+    #[test]
+    fn steppers_step_through_the_examples() {
+        for day in registry::days() {
+            for part in [Part::One, Part::Two] {
+                let Some(stepper) = registry::stepper(day, part) else {
+                    continue;
+                };
+                let mut simulation =
+                    stepper(&example(day)).unwrap_or_else(|e| panic!("day {day}: {e:#}"));
+                let picture = simulation.picture();
+                for (key, _) in simulation.moves() {
+                    assert!(simulation.advance(*key), "day {day} {part:?}: {key}");
+                    let next = simulation.picture();
+                    assert_eq!(next.width(), picture.width(), "day {day} {part:?}");
+                    assert_eq!(next.height(), picture.height(), "day {day} {part:?}");
+                    assert!(!simulation.status().is_empty());
+                }
+            }
+        }
+    }
+}
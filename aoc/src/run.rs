@@ -0,0 +1,265 @@
+use core::time::Duration;
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{bail, ensure, Context};
+use aoc::{inputs_dir, registry};
+use clap::ValueEnum;
+use common::{answers::Answers, Answer, Part, Solution};
+
+use crate::Day;
+
+pub(crate) fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        let mut s = String::new();
+        std::io::stdin()
+            .read_to_string(&mut s)
+            .context("failed to read the input from stdin")?;
+        Ok(s)
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read the input from {}", path.display()))
+    }
+}
+
+/// An input file, along with the answers recorded for the files next to it
+struct Input {
+    path: PathBuf,
+    file: String,
+    answers: Answers,
+}
+
+impl Input {
+    fn read(path: PathBuf) -> anyhow::Result<Self> {
+        let file = read_input(&path)?;
+        let answers = match path.parent() {
+            Some(dir) if path != Path::new("-") => Answers::load(dir)?,
+            _ => Answers::default(),
+        };
+        Ok(Self {
+            path,
+            file,
+            answers,
+        })
+    }
+
+    fn name(&self) -> anyhow::Result<&str> {
+        self.path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .with_context(|| format!("can't record answers for {}", self.path.display()))
+    }
+
+    fn expected(&self, part: Part) -> Option<&str> {
+        self.answers.expected(self.name().ok()?, part)
+    }
+
+    fn record(&mut self, part: Part, answer: &Answer) -> anyhow::Result<()> {
+        let name = self.name()?.to_string();
+        let dir = match self.path.parent() {
+            Some(dir) if self.path != Path::new("-") => dir,
+            _ => bail!("can't record answers for stdin"),
+        };
+        self.answers.record(&name, part, answer);
+        self.answers.save(dir)
+    }
+}
+
+fn time_solution(solution: &dyn Solution, file: &str) -> anyhow::Result<(Answer, Duration)> {
+    let _span =
+        tracing::info_span!("solve", day = solution.day(), part = %solution.part()).entered();
+    let start = Instant::now();
+    let answer = solution.solve(file)?;
+    Ok((answer, start.elapsed()))
+}
+
+/// The solutions to run for `day` and `part`
+fn select(
+    day: Day,
+    part: Option<Part>,
+    input: Option<&Path>,
+) -> anyhow::Result<Vec<&'static dyn Solution>> {
+    let is_selected = |s: &&dyn Solution| part.is_none() || part == Some(s.part());
+    let solutions: Vec<_> = match day {
+        Day::All => {
+            ensure!(input.is_none(), "`--input` can't be used with `all`");
+            // the days which only come with examples have nothing to run by default
+            let (with_real, skipped): (Vec<_>, Vec<_>) =
+                registry::days().partition(|&day| inputs_dir(day).join("real.txt").is_file());
+            if !skipped.is_empty() {
+                let skipped: Vec<_> = skipped.iter().map(|day| format!("{day:02}")).collect();
+                let days = if skipped.len() == 1 { "day" } else { "days" };
+                eprintln!(
+                    "skipping {days} {}, without an inputs/real.txt",
+                    skipped.join(", ")
+                );
+            }
+            registry::solutions()
+                .filter(is_selected)
+                .filter(|s| with_real.contains(&s.day()))
+                .collect()
+        }
+        Day::One(day) => {
+            let solutions: Vec<_> = registry::solutions()
+                .filter(|s| s.day() == day)
+                .filter(is_selected)
+                .collect();
+            match part {
+                _ if !solutions.is_empty() => {}
+                Some(part) => bail!("no solution registered for day {day}, part {part}"),
+                None => bail!("no solution registered for day {day}"),
+            }
+            solutions
+        }
+    };
+    Ok(solutions)
+}
+
+pub(crate) fn run(
+    day: Day,
+    part: Option<Part>,
+    input: Option<&Path>,
+    record: bool,
+) -> anyhow::Result<bool> {
+    let solutions = select(day, part, input)?;
+
+    let mut all_ok = true;
+    let mut last_input: Option<(u8, Input)> = None;
+    for solution in solutions {
+        let (day, part) = (solution.day(), solution.part());
+
+        // both parts of a day share the input, so only read it once
+        // (which also makes `--input -` work for them)
+        if !matches!(&last_input, Some((d, _)) if *d == day) {
+            let path = input.map_or_else(|| inputs_dir(day).join("real.txt"), Path::to_path_buf);
+            last_input = Some((day, Input::read(path)?));
+        }
+        let (_, input) = last_input.as_mut().expect("just read the input");
+
+        let (answer, elapsed) = match time_solution(solution, &input.file) {
+            Ok(res) => res,
+            Err(e) => {
+                all_ok = false;
+                println!("day {day:02}, part {part}: error: {e:#}");
+                continue;
+            }
+        };
+
+        let verdict = match input.expected(part) {
+            _ if record => {
+                input.record(part, &answer)?;
+                " [recorded]".to_string()
+            }
+            None => String::new(),
+            Some(expected) if expected == answer.to_string() => " [ok]".to_string(),
+            Some(expected) => {
+                all_ok = false;
+                format!(" [MISMATCH: expected {expected}]")
+            }
+        };
+        println!("day {day:02}, part {part}: {answer} ({elapsed:?}){verdict}");
+    }
+    Ok(all_ok)
+}
+
+/// Runs the solutions for `day` against every input in `dir`, printing a matrix of the outcomes
+pub(crate) fn batch(
+    day: Day,
+    part: Option<Part>,
+    dir: &Path,
+    record: bool,
+) -> anyhow::Result<bool> {
+    let Day::One(_) = day else {
+        bail!("a single day is needed to run against a directory");
+    };
+    ensure!(!record, "`--record` can't be used with a directory");
+    let solutions = select(day, part, Some(dir))?;
+
+    let matrix = common::batch::run(&solutions, dir)?;
+    print!("{matrix}");
+    Ok(matrix.all_ok())
+}
+
+/// How [`dump`] prints the parsed inputs
+#[derive(Clone, Copy, ValueEnum)]
+pub(crate) enum DumpFormat {
+    /// A JSON object per line, with the `day`, the `part` and the `parsed` input
+    Json,
+}
+
+/// Prints the inputs the way the solutions parse them, without solving them
+pub(crate) fn dump(
+    day: Day,
+    part: Option<Part>,
+    input: Option<&Path>,
+    format: DumpFormat,
+) -> anyhow::Result<bool> {
+    ensure!(
+        cfg!(feature = "serde"),
+        "the runner has to be built with the `serde` feature to dump the parsed inputs"
+    );
+    let solutions = select(day, part, input)?;
+
+    let mut all_ok = true;
+    let mut last_input: Option<(u8, String)> = None;
+    for solution in solutions {
+        let (day, part) = (solution.day(), solution.part());
+
+        if !matches!(&last_input, Some((d, _)) if *d == day) {
+            let path = input.map_or_else(|| inputs_dir(day).join("real.txt"), Path::to_path_buf);
+            last_input = Some((day, read_input(&path)?));
+        }
+        let (_, file) = last_input.as_ref().expect("just read the input");
+
+        match dump_parsed(solution, file, format) {
+            Ok(dumped) => println!("{dumped}"),
+            Err(e) => {
+                all_ok = false;
+                // stdout is for the dumps only
+                eprintln!("day {day:02}, part {part}: error: {e:#}");
+            }
+        }
+    }
+    Ok(all_ok)
+}
+
+#[cfg(feature = "serde")]
+fn dump_parsed(solution: &dyn Solution, file: &str, format: DumpFormat) -> anyhow::Result<String> {
+    let parsed = solution.parse(file)?.to_json()?;
+    match format {
+        DumpFormat::Json => {
+            let dumped = serde_json::json!({
+                "day": solution.day(),
+                "part": u8::from(solution.part()),
+                "parsed": parsed,
+            });
+            Ok(dumped.to_string())
+        }
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn dump_parsed(_: &dyn Solution, _: &str, _: DumpFormat) -> anyhow::Result<String> {
+    bail!("the runner has to be built with the `serde` feature to dump the parsed inputs")
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_the_examples_dump() {
+        for solution in registry::solutions() {
+            let (day, part) = (solution.day(), solution.part());
+            let file = std::fs::read_to_string(aoc::first_example(day).unwrap()).unwrap();
+            let dumped = dump_parsed(solution, &file, DumpFormat::Json)
+                .unwrap_or_else(|e| panic!("day {day}, part {part}: {e:#}"));
+            let value: serde_json::Value = serde_json::from_str(&dumped).unwrap();
+            assert_eq!(value["day"], day);
+            assert!(!value["parsed"].is_null(), "day {day}, part {part}");
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use core::ops::ControlFlow;
+use std::path::Path;
+
+use anyhow::Context;
+use aoc::{inputs_dir, registry};
+use common::Part;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use grid::picture::{Rgb, Simulation};
+use itertools::Itertools;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::run::read_input;
+
+pub(crate) fn step(day: u8, part: Part, input: Option<&Path>) -> anyhow::Result<bool> {
+    let stepper = registry::stepper(day, part)
+        .with_context(|| format!("no stepper registered for day {day} part {part:?}"))?;
+
+    let path = input.map_or_else(|| inputs_dir(day).join("real.txt"), Path::to_path_buf);
+    let file = read_input(&path)?;
+    let mut app = App::new(stepper(&file)?);
+
+    let mut terminal = ratatui::try_init().context("failed to set up the terminal")?;
+    let res = app.run(&mut terminal);
+    ratatui::try_restore().context("failed to restore the terminal")?;
+    res?;
+    Ok(true)
+}
+
+/// The states the simulation went through, to be able to go back and forth between them
+struct App {
+    history: Vec<Box<dyn Simulation>>,
+    cursor: usize,
+    /// How many rows and columns of the picture are scrolled past
+    scroll: (u16, u16),
+}
+
+impl App {
+    fn new(simulation: Box<dyn Simulation>) -> Self {
+        Self {
+            history: vec![simulation],
+            cursor: 0,
+            scroll: (0, 0),
+        }
+    }
+
+    fn current(&self) -> &dyn Simulation {
+        self.history[self.cursor].as_ref()
+    }
+
+    fn run(&mut self, terminal: &mut ratatui::DefaultTerminal) -> anyhow::Result<()> {
+        loop {
+            terminal
+                .draw(|frame| self.draw(frame))
+                .context("failed to draw")?;
+            if let Event::Key(key) = event::read().context("failed to read an event")? {
+                if key.kind == KeyEventKind::Press && self.on_key(key.code).is_break() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn on_key(&mut self, code: KeyCode) -> ControlFlow<()> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return ControlFlow::Break(()),
+            KeyCode::Left => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            KeyCode::Right => {
+                self.forward();
+            }
+            KeyCode::Up => self.scroll.0 = self.scroll.0.saturating_sub(1),
+            KeyCode::Down => self.scroll.0 = self.scroll.0.saturating_add(1),
+            KeyCode::Char('<') => self.scroll.1 = self.scroll.1.saturating_sub(1),
+            KeyCode::Char('>') => self.scroll.1 = self.scroll.1.saturating_add(1),
+            KeyCode::Char(key) => {
+                self.advance(key);
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    /// Goes to the next state, advancing the default way if there isn't one yet,
+    /// returning `false` if there's nowhere to go
+    fn forward(&mut self) -> bool {
+        if self.cursor + 1 < self.history.len() {
+            self.cursor += 1;
+            return true;
+        }
+        // a simulation without any moves just stays where it is
+        let Some(&(key, _)) = self.current().moves().first() else {
+            return false;
+        };
+        self.advance(key)
+    }
+
+    /// Advances the simulation the way bound to `key`, forgetting the states after the current one
+    fn advance(&mut self, key: char) -> bool {
+        let mut next = self.current().boxed_clone();
+        if !next.advance(key) {
+            return false;
+        }
+        self.history.truncate(self.cursor + 1);
+        self.history.push(next);
+        self.cursor += 1;
+        true
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [header, body] =
+            Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]).areas(frame.area());
+
+        let simulation = self.current();
+        let moves = simulation
+            .moves()
+            .iter()
+            .map(|(key, description)| format!("{key}: {description}"))
+            .join(", ");
+        let help = format!("←/→: back/forward, {moves}, ↑/↓/</>: scroll, q: quit");
+        let status = format!(
+            "[{}/{}] {}",
+            self.cursor,
+            self.history.len() - 1,
+            simulation.status()
+        );
+        frame.render_widget(
+            Paragraph::new(vec![Line::raw(status), Line::raw(help)])
+                .block(Block::default().borders(Borders::BOTTOM)),
+            header,
+        );
+
+        let lines = simulation
+            .picture()
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|tile| {
+                        let style = Style::default().fg(colour(tile.fg)).bg(colour(tile.bg));
+                        Span::styled(tile.glyph.to_string(), style)
+                    })
+                    .collect::<Line>()
+            })
+            .collect::<Vec<_>>();
+        frame.render_widget(Paragraph::new(lines).scroll(self.scroll), body);
+    }
+}
+
+fn colour(Rgb(r, g, b): Rgb) -> Color {
+    Color::Rgb(r, g, b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use grid::{
+        picture::{Picture, Tile},
+        Grid,
+    };
+    use ratatui::{backend::TestBackend, Terminal};
+
+    /// A counter which can go up by one or two, up to 3
+    #[derive(Clone)]
+    struct Counter(usize);
+
+    impl Simulation for Counter {
+        fn moves(&self) -> &'static [(char, &'static str)] {
+            &[('a', "add one"), ('b', "add two")]
+        }
+
+        fn advance(&mut self, key: char) -> bool {
+            let n = match key {
+                'a' => 1,
+                'b' => 2,
+                _ => return false,
+            };
+            if self.0 + n > 3 {
+                return false;
+            }
+            self.0 += n;
+            true
+        }
+
+        fn picture(&self) -> Picture {
+            let row = (0..3)
+                .map(|i| Tile::new(if i < self.0 { '#' } else { '.' }, Rgb::WHITE))
+                .collect();
+            Grid::from_rows([row]).unwrap()
+        }
+
+        fn status(&self) -> String {
+            format!("count: {}", self.0)
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Simulation> {
+            Box::new(self.clone())
+        }
+    }
+
+    /// A simulation which can't be advanced at all
+    #[derive(Clone)]
+    struct Stuck;
+
+    impl Simulation for Stuck {
+        fn moves(&self) -> &'static [(char, &'static str)] {
+            &[]
+        }
+
+        fn advance(&mut self, _: char) -> bool {
+            false
+        }
+
+        fn picture(&self) -> Picture {
+            Grid::from_rows([vec![Tile::new('.', Rgb::WHITE)]]).unwrap()
+        }
+
+        fn status(&self) -> String {
+            String::from("stuck")
+        }
+
+        fn boxed_clone(&self) -> Box<dyn Simulation> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        assert!(app.on_key(code).is_continue());
+    }
+
+    fn count(app: &App) -> String {
+        app.current().status()
+    }
+
+    #[test]
+    fn forward_advances_the_default_way_at_the_end() {
+        let mut app = App::new(Box::new(Counter(0)));
+        assert!(app.forward());
+        assert!(app.forward());
+        assert_eq!(count(&app), "count: 2");
+        assert!(app.forward());
+        assert!(!app.forward());
+        assert_eq!(count(&app), "count: 3");
+    }
+
+    #[test]
+    fn forward_without_moves_stays_put() {
+        let mut app = App::new(Box::new(Stuck));
+        assert!(!app.forward());
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.history.len(), 1);
+    }
+
+    #[test]
+    fn moves_after_going_back_forget_the_states_after() {
+        let mut app = App::new(Box::new(Counter(0)));
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Char('a'));
+        }
+        press(&mut app, KeyCode::Left);
+        press(&mut app, KeyCode::Left);
+        assert_eq!(count(&app), "count: 1");
+
+        // going forward again goes through the same states
+        press(&mut app, KeyCode::Right);
+        assert_eq!(count(&app), "count: 2");
+        press(&mut app, KeyCode::Left);
+
+        press(&mut app, KeyCode::Char('b'));
+        assert_eq!(count(&app), "count: 3");
+        assert_eq!(app.history.len(), 3);
+        assert!(!app.forward());
+    }
+
+    #[test]
+    fn moves_which_cant_be_made_change_nothing() {
+        let mut app = App::new(Box::new(Counter(2)));
+        press(&mut app, KeyCode::Char('b'));
+        press(&mut app, KeyCode::Char('x'));
+        assert_eq!(count(&app), "count: 2");
+        assert_eq!(app.history.len(), 1);
+    }
+
+    #[test]
+    fn quits_on_q_and_esc() {
+        let mut app = App::new(Box::new(Counter(0)));
+        assert!(app.on_key(KeyCode::Char('q')).is_break());
+        assert!(app.on_key(KeyCode::Esc).is_break());
+        assert!(app.on_key(KeyCode::Char('a')).is_continue());
+    }
+
+    #[test]
+    fn draws_the_status_and_the_picture() {
+        let mut app = App::new(Box::new(Counter(0)));
+        press(&mut app, KeyCode::Char('b'));
+        let mut terminal = Terminal::new(TestBackend::new(80, 5)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let line = |y| {
+            (0..buffer.area.width)
+                .map(|x| buffer[(x, y)].symbol())
+                .collect::<String>()
+        };
+        assert!(line(0).starts_with("[1/1] count: 2"));
+        assert!(line(1).contains("a: add one, b: add two"));
+        assert!(line(3).starts_with("##."));
+        assert_eq!(buffer[(0, 3)].fg, Color::Rgb(0xf0, 0xf0, 0xf0));
+    }
+}
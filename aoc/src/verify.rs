@@ -0,0 +1,24 @@
+use anyhow::Context;
+use aoc::{inputs_dir, registry};
+use common::answers::{self, Report};
+
+use crate::Day;
+
+pub(crate) fn verify(day: Day) -> anyhow::Result<bool> {
+    let days: Vec<_> = match day {
+        Day::All => registry::days().collect(),
+        Day::One(day) => vec![day],
+    };
+
+    let mut report = Report::default();
+    for day in days {
+        let solutions =
+            registry::day(day).with_context(|| format!("no solution registered for day {day}"))?;
+        report
+            .0
+            .extend(answers::verify(solutions, &inputs_dir(day))?.0);
+    }
+
+    print!("{report}");
+    Ok(report.all_match())
+}
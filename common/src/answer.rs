@@ -0,0 +1,83 @@
+use core::fmt::Display;
+
+/// The answer to a part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Unsigned(n) => write!(f, "{n}"),
+            Self::Text(s) => f.write_str(s),
+        }
+    }
+}
+
+/// What a solving function may return: either the answer itself, or a result with it
+pub trait IntoAnswer {
+    fn into_answer(self) -> anyhow::Result<Answer>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for anyhow::Result<T> {
+    fn into_answer(self) -> anyhow::Result<Answer> {
+        self.map(Into::into)
+    }
+}
+
+macro_rules! impl_from_for_answer {
+    ($variant:ident as $inner:ty: $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::$variant(value as $inner)
+                }
+            }
+
+            impl IntoAnswer for $t {
+                fn into_answer(self) -> anyhow::Result<Answer> {
+                    Ok(self.into())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_for_answer!(Signed as i64: i8, i16, i32, i64, isize);
+impl_from_for_answer!(Unsigned as u64: u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> anyhow::Result<Answer> {
+        Ok(self.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&Answer::from(55_712u32) => "55712")]
+    #[test_case(&Answer::from(-3i32) => "-3")]
+    #[test_case(&Answer::from(13_740_108_158_591u64) => "13740108158591")]
+    #[test_case(&Answer::from("PGXR") => "PGXR")]
+    fn display(answer: &Answer) -> String {
+        answer.to_string()
+    }
+}
@@ -0,0 +1,304 @@
+use core::fmt::Display;
+use std::{
+    collections::BTreeMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+};
+
+use anyhow::{bail, Context};
+use toml::{Table, Value};
+
+use crate::{Answer, Part, Solution};
+
+/// Name of the manifest in an inputs directory
+pub const MANIFEST: &str = "answers.toml";
+
+/// Expected answers for the files in an inputs directory
+///
+/// The manifest is keyed by input file name, and then by part:
+/// ```toml
+/// ["real.txt"]
+/// p1 = 55712
+/// p2 = 55413
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<String, BTreeMap<Part, String>>);
+
+fn part_key(part: Part) -> String {
+    format!("p{part}")
+}
+
+/// The part keyed by `p1` or `p2`
+pub(crate) fn parse_part_key(key: &str) -> Option<Part> {
+    match key {
+        "p1" => Some(Part::One),
+        "p2" => Some(Part::Two),
+        _ => None,
+    }
+}
+
+/// An answer written down as a number or a string
+pub(crate) fn parse_answer(answer: Value) -> Option<String> {
+    match answer {
+        Value::Integer(n) => Some(n.to_string()),
+        Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+impl Answers {
+    /// Loads the manifest from `inputs_dir`, if there is one
+    pub fn load(inputs_dir: &Path) -> anyhow::Result<Self> {
+        let path = inputs_dir.join(MANIFEST);
+        match std::fs::read_to_string(&path) {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("invalid manifest: {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, inputs_dir: &Path) -> anyhow::Result<()> {
+        let path = inputs_dir.join(MANIFEST);
+        std::fs::write(&path, self.to_string())
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn expected(&self, input: &str, part: Part) -> Option<&str> {
+        self.0.get(input)?.get(&part).map(String::as_str)
+    }
+
+    pub fn record(&mut self, input: &str, part: Part, answer: &Answer) {
+        self.0
+            .entry(input.to_string())
+            .or_default()
+            .insert(part, answer.to_string());
+    }
+
+    /// All the recorded answers as `(input, part, expected answer)`
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Part, &str)> {
+        self.0.iter().flat_map(|(input, parts)| {
+            parts
+                .iter()
+                .map(move |(&part, expected)| (input.as_str(), part, expected.as_str()))
+        })
+    }
+}
+
+impl core::str::FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s.parse()?;
+
+        let mut res: BTreeMap<String, BTreeMap<_, _>> = BTreeMap::new();
+        for (input, parts) in table {
+            let Value::Table(parts) = parts else {
+                bail!("answers for {input} are not a table");
+            };
+            let recorded = res.entry(input.clone()).or_default();
+            for (key, answer) in parts {
+                let Some(part) = parse_part_key(&key) else {
+                    bail!("invalid part for {input}: {key}");
+                };
+                let Some(answer) = parse_answer(answer.clone()) else {
+                    bail!("invalid answer for {input}, {key}: {answer}");
+                };
+                recorded.insert(part, answer);
+            }
+        }
+        Ok(Self(res))
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let table: Table = self
+            .0
+            .iter()
+            .map(|(input, parts)| {
+                let parts: Table = parts
+                    .iter()
+                    .map(|(&part, answer)| {
+                        // keep numbers as numbers where TOML allows it
+                        let answer = answer
+                            .parse()
+                            .map_or_else(|_| Value::String(answer.clone()), Value::Integer);
+                        (part_key(part), answer)
+                    })
+                    .collect();
+                (input.clone(), Value::Table(parts))
+            })
+            .collect();
+        write!(f, "{table}")
+    }
+}
+
+/// The result of running a solution against an input with a recorded answer
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+    pub actual: Result<Answer, String>,
+}
+
+impl Check {
+    pub fn is_match(&self) -> bool {
+        matches!(&self.actual, Ok(a) if a.to_string() == self.expected)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report(pub Vec<Check>);
+
+impl Report {
+    pub fn all_match(&self) -> bool {
+        self.0.iter().all(Check::is_match)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        const HEADER: [&str; 6] = ["day", "part", "input", "expected", "actual", ""];
+
+        let rows: Vec<[String; 6]> = self
+            .0
+            .iter()
+            .map(|c| {
+                let actual = match &c.actual {
+                    Ok(a) => a.to_string(),
+                    Err(e) => format!("error: {e}"),
+                };
+                let status = if c.is_match() { "ok" } else { "MISMATCH" };
+                [
+                    format!("{:02}", c.day),
+                    c.part.to_string(),
+                    c.input.clone(),
+                    c.expected.clone(),
+                    actual,
+                    status.to_string(),
+                ]
+            })
+            .collect();
+
+        write_table(f, &HEADER, &rows)
+    }
+}
+
+/// Writes `rows` under `header`, with each column as wide as its widest cell
+pub(crate) fn write_table(
+    f: &mut core::fmt::Formatter<'_>,
+    header: &[&str],
+    rows: &[impl AsRef<[String]>],
+) -> core::fmt::Result {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r.as_ref()[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let write_row = |f: &mut core::fmt::Formatter<'_>, row: &[&str]| {
+        let line = core::iter::zip(row, &widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(f, "{}", line.trim_end())
+    };
+
+    write_row(f, header)?;
+    let separator: Vec<_> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    writeln!(f, "{}", separator.join("-+-"))?;
+    for row in rows {
+        let row: Vec<_> = row.as_ref().iter().map(String::as_str).collect();
+        write_row(f, &row)?;
+    }
+    Ok(())
+}
+
+/// Runs every solution against every input in `inputs_dir` that has a recorded answer for it
+pub fn verify(solutions: &[&dyn Solution], inputs_dir: &Path) -> anyhow::Result<Report> {
+    let answers = Answers::load(inputs_dir)?;
+
+    let mut checks = vec![];
+    for (input, part, expected) in answers.iter() {
+        let Some(solution) = solutions.iter().find(|s| s.part() == part) else {
+            continue;
+        };
+        let path = inputs_dir.join(input);
+        let file = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        // a panicking solution shouldn't take the rest of the report down with it
+        let actual = match catch_unwind(AssertUnwindSafe(|| solution.solve(&file))) {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(e)) => Err(format!("{e:#}")),
+            Err(_) => Err("panicked".to_string()),
+        };
+
+        checks.push(Check {
+            day: solution.day(),
+            part,
+            input: input.to_string(),
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+    Ok(Report(checks))
+}
+
+/// Generates a test checking `$solutions` against the answers
+/// recorded in the calling crate's `inputs/answers.toml`
+#[macro_export]
+macro_rules! answer_tests {
+    ($solutions:expr) => {
+        #[test]
+        fn answers() {
+            let inputs = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+            let report = $crate::answers::verify($solutions, &inputs).unwrap();
+            assert!(report.all_match(), "answers don't match:\n{report}");
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MANIFEST: &str = r#"["example.txt"]
+p1 = 142
+
+["real.txt"]
+p1 = 55712
+p2 = "PGXR"
+"#;
+
+    #[test]
+    fn parse() {
+        let answers: Answers = MANIFEST.parse().unwrap();
+        assert_eq!(answers.expected("example.txt", Part::One), Some("142"));
+        assert_eq!(answers.expected("example.txt", Part::Two), None);
+        assert_eq!(answers.expected("real.txt", Part::Two), Some("PGXR"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let answers: Answers = MANIFEST.parse().unwrap();
+        assert_eq!(answers.to_string(), MANIFEST);
+    }
+
+    #[test]
+    fn record() {
+        let mut answers: Answers = MANIFEST.parse().unwrap();
+        answers.record("example.txt", Part::Two, &Answer::from(281u32));
+        answers.record("real.txt", Part::One, &Answer::from(1u32));
+        assert_eq!(answers.expected("example.txt", Part::Two), Some("281"));
+        assert_eq!(answers.expected("real.txt", Part::One), Some("1"));
+    }
+}
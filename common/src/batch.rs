@@ -0,0 +1,355 @@
+//! Running a day's solutions against every input in a directory
+//!
+//! Which parts apply to an input, and what their answers should be, comes from an optional
+//! sidecar next to it, named like the input but with a `.toml` extension:
+//! ```toml
+//! # only part 2 makes sense for this input
+//! parts = [2]
+//! p2 = 4
+//! ```
+//! Without a sidecar, the answers recorded in the directory's manifest are expected,
+//! and only the parts with one are run. Inputs which are in neither get both parts run
+
+use core::{fmt::Display, time::Duration};
+use std::{
+    collections::BTreeMap,
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    time::Instant,
+};
+
+use anyhow::{bail, Context};
+use toml::{Table, Value};
+
+use crate::{
+    answers::{self, Answers},
+    Answer, Part, Solution,
+};
+
+/// Extension of the sidecars
+const SIDECAR_EXTENSION: &str = "toml";
+
+/// What an input's sidecar declares about it
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Sidecar {
+    /// The parts which apply to the input, all of them if `None`
+    parts: Option<Vec<Part>>,
+    expected: BTreeMap<Part, String>,
+}
+
+impl core::str::FromStr for Sidecar {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s.parse()?;
+
+        let mut sidecar = Self::default();
+        for (key, value) in table {
+            if key == "parts" {
+                let Value::Array(parts) = value else {
+                    bail!("`parts` is not a list of parts");
+                };
+                let parts = parts
+                    .into_iter()
+                    .map(|part| match part {
+                        Value::Integer(n) => u8::try_from(n)
+                            .map_err(anyhow::Error::from)
+                            .and_then(Part::try_from),
+                        v => bail!("invalid part: {v}"),
+                    })
+                    .collect::<anyhow::Result<_>>()?;
+                sidecar.parts = Some(parts);
+                continue;
+            }
+            let Some(part) = answers::parse_part_key(&key) else {
+                bail!("invalid key: {key}");
+            };
+            let Some(answer) = answers::parse_answer(value.clone()) else {
+                bail!("invalid answer for {key}: {value}");
+            };
+            sidecar.expected.insert(part, answer);
+        }
+        Ok(sidecar)
+    }
+}
+
+impl Sidecar {
+    /// Loads the sidecar of `input`, falling back to what `answers` has recorded for it
+    fn load(input: &Path, answers: &Answers) -> anyhow::Result<Self> {
+        let path = input.with_extension(SIDECAR_EXTENSION);
+        match std::fs::read_to_string(&path) {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("invalid sidecar: {}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let name = file_name(input);
+                let expected: BTreeMap<_, _> = [Part::One, Part::Two]
+                    .into_iter()
+                    .filter_map(|part| Some((part, answers.expected(&name, part)?.to_string())))
+                    .collect();
+                let parts = (!expected.is_empty()).then(|| expected.keys().copied().collect());
+                Ok(Self { parts, expected })
+            }
+            Err(e) => Err(e).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    fn applies_to(&self, part: Part) -> bool {
+        match &self.parts {
+            None => true,
+            Some(parts) => parts.contains(&part),
+        }
+    }
+}
+
+/// What came out of running a part against an input
+#[derive(Debug)]
+pub enum Outcome {
+    /// The part doesn't apply to the input
+    Skipped,
+    Solved {
+        answer: Answer,
+        elapsed: Duration,
+        expected: Option<String>,
+    },
+    Failed(String),
+}
+
+impl Outcome {
+    /// Whether nothing went wrong, which is the case for answers nobody knows yet too
+    pub fn is_ok(&self) -> bool {
+        match self {
+            Self::Skipped | Self::Solved { expected: None, .. } => true,
+            Self::Solved {
+                answer,
+                expected: Some(expected),
+                ..
+            } => *expected == answer.to_string(),
+            Self::Failed(_) => false,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Skipped => write!(f, "-"),
+            Self::Solved {
+                answer,
+                elapsed,
+                expected,
+            } => {
+                write!(f, "{answer} ({elapsed:.1?})")?;
+                match expected {
+                    None => Ok(()),
+                    Some(_) if self.is_ok() => write!(f, " [ok]"),
+                    Some(expected) => write!(f, " [MISMATCH: expected {expected}]"),
+                }
+            }
+            Self::Failed(e) => write!(f, "error: {e}"),
+        }
+    }
+}
+
+/// The outcome of each of the parts on each of the inputs
+#[derive(Debug)]
+pub struct Matrix {
+    pub parts: Vec<Part>,
+    /// The inputs' file names, along with the outcomes in the order of `parts`
+    pub rows: Vec<(String, Vec<Outcome>)>,
+}
+
+impl Matrix {
+    pub fn all_ok(&self) -> bool {
+        self.rows
+            .iter()
+            .flat_map(|(_, outcomes)| outcomes)
+            .all(Outcome::is_ok)
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let parts: Vec<_> = self
+            .parts
+            .iter()
+            .map(|part| format!("part {part}"))
+            .collect();
+        let header: Vec<_> = ["input"]
+            .into_iter()
+            .chain(parts.iter().map(String::as_str))
+            .collect();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|(input, outcomes)| {
+                [input.clone()]
+                    .into_iter()
+                    .chain(outcomes.iter().map(Outcome::to_string))
+                    .collect()
+            })
+            .collect();
+        answers::write_table(f, &header, &rows)
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// The inputs in `dir`: all the files but the sidecars (and the manifest, which is TOML too),
+/// sorted by name
+fn inputs(dir: &Path) -> anyhow::Result<Vec<std::path::PathBuf>> {
+    let mut inputs = vec![];
+    for entry in
+        std::fs::read_dir(dir).with_context(|| format!("failed to list {}", dir.display()))?
+    {
+        let path = entry
+            .with_context(|| format!("failed to list {}", dir.display()))?
+            .path();
+        let is_sidecar = path
+            .extension()
+            .is_some_and(|extension| extension == SIDECAR_EXTENSION);
+        if path.is_file() && !is_sidecar {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Runs each of `solutions` against each of the inputs in `dir` it applies to
+pub fn run(solutions: &[&dyn Solution], dir: &Path) -> anyhow::Result<Matrix> {
+    let answers = Answers::load(dir)?;
+
+    let mut rows = vec![];
+    for path in inputs(dir)? {
+        let sidecar = Sidecar::load(&path, &answers)?;
+        let file = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        let outcomes = solutions
+            .iter()
+            .map(|solution| {
+                let part = solution.part();
+                if !sidecar.applies_to(part) {
+                    return Outcome::Skipped;
+                }
+                let start = Instant::now();
+                // a panicking solution shouldn't take the rest of the matrix down with it
+                match catch_unwind(AssertUnwindSafe(|| solution.solve(&file))) {
+                    Ok(Ok(answer)) => Outcome::Solved {
+                        answer,
+                        elapsed: start.elapsed(),
+                        expected: sidecar.expected.get(&part).cloned(),
+                    },
+                    Ok(Err(e)) => Outcome::Failed(format!("{e:#}")),
+                    Err(_) => Outcome::Failed("panicked".to_string()),
+                }
+            })
+            .collect();
+        rows.push((file_name(&path), outcomes));
+    }
+
+    Ok(Matrix {
+        parts: solutions.iter().map(|s| s.part()).collect(),
+        rows,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{answers::MANIFEST, parsed, Parsed};
+
+    /// Part 1 counts the lines, part 2 only works with a single line
+    struct Lines(Part);
+
+    impl Solution for Lines {
+        fn day(&self) -> u8 {
+            0
+        }
+        fn part(&self) -> Part {
+            self.0
+        }
+        fn parse<'a>(&self, file: &'a str) -> anyhow::Result<Box<dyn Parsed + 'a>> {
+            let n_lines = file.lines().count();
+            match self.0 {
+                Part::One => Ok(parsed(n_lines, |n| n)),
+                Part::Two if n_lines == 1 => Ok(parsed(file, str::len)),
+                Part::Two => bail!("more than one line"),
+            }
+        }
+    }
+
+    const SOLUTIONS: &[&dyn Solution] = &[&Lines(Part::One), &Lines(Part::Two)];
+
+    /// A fresh directory with `files` in it
+    fn dir(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("batch-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (name, contents) in files {
+            std::fs::write(dir.join(name), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn sidecar() {
+        let sidecar: Sidecar = "parts = [2]\np2 = 4".parse().unwrap();
+        assert!(!sidecar.applies_to(Part::One));
+        assert!(sidecar.applies_to(Part::Two));
+        assert_eq!(
+            sidecar.expected.get(&Part::Two).map(String::as_str),
+            Some("4")
+        );
+
+        assert!("parts = [3]".parse::<Sidecar>().is_err());
+        assert!("p3 = 1".parse::<Sidecar>().is_err());
+    }
+
+    #[test]
+    fn runs_the_parts_which_apply() {
+        let dir = dir(
+            "apply",
+            &[
+                ("one.txt", "abc"),
+                ("two.txt", "a\nb"),
+                ("two.toml", "parts = [1]\np1 = 2"),
+                ("three.txt", "a\nb\nc"),
+                (MANIFEST, "[\"one.txt\"]\np1 = 1\np2 = 4"),
+            ],
+        );
+        let matrix = run(SOLUTIONS, &dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let inputs: Vec<_> = matrix
+            .rows
+            .iter()
+            .map(|(input, _)| input.as_str())
+            .collect();
+        assert_eq!(inputs, ["one.txt", "three.txt", "two.txt"]);
+
+        let outcome = |input: usize, part: usize| &matrix.rows[input].1[part];
+        // the manifest expects the wrong answer for part 2
+        assert!(outcome(0, 0).is_ok());
+        assert!(!outcome(0, 1).is_ok());
+        // nothing is known about `three.txt`, so both parts are run
+        assert!(matches!(
+            outcome(1, 0),
+            Outcome::Solved { expected: None, .. }
+        ));
+        assert!(matches!(outcome(1, 1), Outcome::Failed(_)));
+        // and the sidecar of `two.txt` says that part 2 doesn't apply
+        assert!(outcome(2, 0).is_ok());
+        assert!(matches!(outcome(2, 1), Outcome::Skipped));
+
+        assert!(!matrix.all_ok());
+        let table = matrix.to_string();
+        assert!(table.starts_with("input"));
+        assert!(table.contains("[MISMATCH: expected 4]"));
+    }
+}
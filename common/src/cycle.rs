@@ -0,0 +1,210 @@
+//! Finding where the states of a deterministic process start repeating
+//!
+//! A process starting from `x0` and going from state to state with `step` will, if it has
+//! finitely many states, end up going in circles: after a prefix of [`Cycle::start`] steps,
+//! the states repeat every [`Cycle::period`] steps. This is what lets a process be
+//! "fast-forwarded" to a step far beyond what could be simulated
+
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use num::Integer;
+
+/// Where the states of a process start repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the first state that repeats
+    pub start: usize,
+    /// The number of steps after which the states repeat
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step `n`
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// The state after `n` steps from `x0`, taking no more than `start + period` steps
+    pub fn nth<S>(&self, x0: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+        (0..self.reduce(n)).fold(x0, |state, _| step(&state))
+    }
+}
+
+/// Finds the cycle with Floyd's tortoise and hare, keeping only two states around at a time
+pub fn floyd<S: Clone + PartialEq>(x0: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the hare goes twice as fast, so they meet `k * period` steps in, for some `k`
+    let mut tortoise = step(&x0);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // so when both go at the same speed, with the hare still `k * period` steps ahead,
+    // they meet as soon as the tortoise enters the cycle
+    let mut start = 0;
+    tortoise = x0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Finds the cycle with Brent's algorithm, which needs fewer steps than [`floyd`]
+pub fn brent<S: Clone + PartialEq>(x0: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise teleports to the hare at each power of two,
+    // until the hare gets back to it, having gone around the cycle once
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(&x0);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // with the hare `period` steps ahead, they meet as soon as the tortoise enters the cycle
+    let mut start = 0;
+    tortoise = x0.clone();
+    hare = (0..period).fold(x0, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Finds the cycle by remembering the `key` of each state, taking only `start + period` steps
+///
+/// `key` has to tell apart all the different states, but can be much more compact than them
+pub fn find_by_key<S, K: Hash + Eq>(
+    x0: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut seen = HashMap::new();
+    let mut state = x0;
+    for i in 0.. {
+        if let Some(j) = seen.insert(key(&state), i) {
+            return Cycle {
+                start: j,
+                period: i - j,
+            };
+        }
+        state = step(&state);
+    }
+    unreachable!("the process has gone through more than `usize::MAX` states")
+}
+
+/// The state after `n` steps from `x0`, skipping the steps which would only repeat the cycle
+///
+/// Like [`find_by_key`], but stops as soon as the cycle is found, or after `n` steps if it isn't
+pub fn nth_by_key<S, K: Hash + Eq>(
+    x0: S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    n: usize,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut state = x0;
+    for i in 0..n {
+        if let Some(j) = seen.insert(key(&state), i) {
+            // step `n` has the same state as step `i + (n - i) % (i - j)`
+            let remaining = (n - i) % (i - j);
+            return (0..remaining).fold(state, |state, _| step(&state));
+        }
+        state = step(&state);
+    }
+    state
+}
+
+/// Combines `t ≡ r1 (mod m1)` and `t ≡ r2 (mod m2)` into a single congruence,
+/// if there are any such `t`
+///
+/// This is how the steps at which several processes going in circles are all in some state are found
+pub fn chinese_remainder((r1, m1): (i128, i128), (r2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let e = m1.extended_gcd(&m2);
+    if (r2 - r1) % e.gcd != 0 {
+        return None;
+    }
+    let m = m1 / e.gcd * m2;
+    // `m1 * e.x ≡ gcd (mod m2)`, so adding `(r2 - r1) / gcd` of that to `r1` gets to `r2`
+    let r = r1 + (r2 - r1) / e.gcd * e.x % (m2 / e.gcd) * m1;
+    Some((r.rem_euclid(m), m))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    /// 0, 1, .., `start - 1`, and then `start`, .., `start + period - 1` over and over again
+    fn rho(start: usize, period: usize) -> impl Fn(&usize) -> usize {
+        move |&x| {
+            if x + 1 < start + period {
+                x + 1
+            } else {
+                start
+            }
+        }
+    }
+
+    #[test_case(0, 1; "fixed point")]
+    #[test_case(0, 7; "pure cycle")]
+    #[test_case(3, 1; "prefix then fixed point")]
+    #[test_case(5, 12; "prefix then cycle")]
+    #[test_case(100, 33; "long prefix")]
+    fn finders_agree(start: usize, period: usize) {
+        let expected = Cycle { start, period };
+        assert_eq!(floyd(0, rho(start, period)), expected);
+        assert_eq!(brent(0, rho(start, period)), expected);
+        assert_eq!(find_by_key(0, rho(start, period), |&x| x), expected);
+    }
+
+    #[test_case(5, 12, 0; "start")]
+    #[test_case(5, 12, 3; "in the prefix")]
+    #[test_case(5, 12, 17; "one period in")]
+    #[test_case(5, 12, 1_000_000_007; "far ahead")]
+    #[test_case(0, 1, 42; "fixed point")]
+    fn fast_forward(start: usize, period: usize, n: usize) {
+        let expected = if n < start + period {
+            n
+        } else {
+            start + (n - start) % period
+        };
+        let cycle = brent(0, rho(start, period));
+        assert_eq!(cycle.nth(0, rho(start, period), n), expected);
+        assert_eq!(nth_by_key(0, rho(start, period), |&x| x, n), expected);
+    }
+
+    #[test_case((2, 3), (3, 5) => Some((8, 15)); "coprime")]
+    #[test_case((2, 4), (0, 6) => Some((6, 12)); "common factor")]
+    #[test_case((1, 4), (0, 6) => None; "incompatible")]
+    #[test_case((0, 1), (4, 7) => Some((4, 7)); "anything")]
+    fn chinese_remainder(c1: (i128, i128), c2: (i128, i128)) -> Option<(i128, i128)> {
+        super::chinese_remainder(c1, c2)
+    }
+}
@@ -0,0 +1,75 @@
+//! Extrapolating sequences by their differences
+//!
+//! The differences between the values of a polynomial of degree `d` are a polynomial of degree
+//! `d - 1`, so after `d + 1` rounds of taking differences they're all zeros. Going back up from
+//! there gives the values before and after the ones known
+
+use core::ops::Sub;
+
+use num::Zero;
+
+/// `values`, then the differences between them, then the differences between those,
+/// and so on, up to (but not including) the first ones which are all zeros
+pub fn differences<T: Zero + Copy + Sub<Output = T>>(values: Vec<T>) -> Vec<Vec<T>> {
+    let mut curr_derivative = values;
+    let mut derivatives = vec![];
+    while !curr_derivative.iter().all(T::is_zero) {
+        // TODO: use `array_windows`
+        let next_derivative = curr_derivative
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .collect();
+        derivatives.push(core::mem::replace(&mut curr_derivative, next_derivative));
+    }
+    derivatives
+}
+
+/// The value after the last of `values`
+pub fn extrapolate<T: Zero + Copy + Sub<Output = T>>(values: Vec<T>) -> T {
+    extrapolate_by(values, 1)
+}
+
+/// The value `n` places after the last of `values`
+pub fn extrapolate_by<T: Zero + Copy + Sub<Output = T>>(values: Vec<T>, n: usize) -> T {
+    // the last value of each of the derivatives, which are moved along together
+    let mut lasts: Vec<T> = differences(values)
+        .into_iter()
+        .map(|v| *v.last().expect("enough data points to extrapolate"))
+        .collect();
+    for _ in 0..n {
+        for i in (1..lasts.len()).rev() {
+            lasts[i - 1] = lasts[i - 1] + lasts[i];
+        }
+    }
+    lasts.first().copied().unwrap_or_else(T::zero)
+}
+
+/// The value before the first of `values`
+pub fn extrapolate_back<T: Zero + Copy + Sub<Output = T>>(values: Vec<T>) -> T {
+    differences(values)
+        .into_iter()
+        .map(|v| *v.first().expect("enough data points to extrapolate"))
+        .rfold(T::zero(), |acc, n| n - acc)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![0, 3, 6, 9, 12, 15], 1 => 18; "linear")]
+    #[test_case(vec![1, 3, 6, 10, 15, 21], 1 => 28; "quadratic")]
+    #[test_case(vec![1, 3, 6, 10, 15, 21], 3 => 45; "quadratic further")]
+    #[test_case(vec![5, 5], 1_000 => 5; "constant")]
+    #[test_case(vec![0, 0], 2 => 0; "zeros")]
+    fn extrapolates_by(values: Vec<i64>, n: usize) -> i64 {
+        extrapolate_by(values, n)
+    }
+
+    #[test]
+    fn extrapolates_far() {
+        // the triangular numbers
+        let values: Vec<i64> = (0..4).map(|n| n * (n + 1) / 2).collect();
+        assert_eq!(extrapolate_by(values, 1_000_000 - 3), 500_000_500_000);
+    }
+}
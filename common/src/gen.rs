@@ -0,0 +1,40 @@
+//! Random, but valid, puzzle inputs, for stress-testing the solutions beyond the checked-in inputs
+//!
+//! Each day has a `gen` module with a [`Generator`]. The inputs depend only on the seed
+//! of the random number generator, so any failure found with them can be reproduced
+
+pub use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+/// Generates an input whose size grows with the `usize` argument
+///
+/// What exactly the size means depends on the day: the side of a grid, the number of lines etc.
+pub type Generator = fn(&mut StdRng, usize) -> String;
+
+/// The random number generator to pass to [`Generator`]s
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Generates a test checking that `$solutions` don't fail on the inputs
+/// generated by `$generator`, for a couple of seeds and each of the `$sizes`
+#[macro_export]
+macro_rules! generated_input_tests {
+    ($generator:expr, $solutions:expr, $sizes:expr) => {
+        #[test]
+        fn generated_inputs() {
+            for size in $sizes {
+                for seed in 0..8 {
+                    let input = $generator(&mut $crate::gen::rng(seed), size);
+                    for solution in $solutions {
+                        if let Err(e) = solution.solve(&input) {
+                            panic!(
+                                "part {} failed on the input of size {size} with seed {seed}: {e:#}\n{input}",
+                                solution.part(),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,12 @@
+mod answer;
+pub mod answers;
+pub mod batch;
+pub mod cycle;
+pub mod differences;
+pub mod gen;
+pub mod parse;
+pub mod read;
+mod solution;
+
+pub use answer::{Answer, IntoAnswer};
+pub use solution::{parsed, Dump, Parsed, Part, Solution};
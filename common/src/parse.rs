@@ -0,0 +1,424 @@
+//! Error reporting shared by the days' nom parsers
+//!
+//! The parsers use [`IResult`] instead of nom's, and name their grammar rules with [`rule`].
+//! Once a parser has been run over the input with [`parse_all`] (or the `FromStr`/`TryFrom`
+//! impls generated by the macros here), a failure gets turned into a [`ParseError`],
+//! which points at the line and column it happened at, and knows what rule was expected there:
+//! ```text
+//! line 2, column 8: expected `node_next`: expected '('
+//!   |
+//! 2 | AAA = BBB, CCC)
+//!   |       ^
+//! ```
+
+use core::fmt::{self, Display};
+use std::borrow::Cow;
+
+use nom::{
+    character::complete::digit1,
+    combinator::map_res,
+    error::{ContextError, ErrorKind, FromExternalError},
+};
+
+/// What went wrong at a spot in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Problem {
+    /// A grammar rule, named with [`rule`] or nom's `context`, failed here
+    Rule(Cow<'static, str>),
+    Char(char),
+    Nom(ErrorKind),
+    /// Converting what was parsed failed, e.g. in a `map_res`
+    External(String),
+}
+
+/// The error of the parsers while they're running:
+/// the spots where things went wrong, innermost first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<I> {
+    stack: Vec<(I, Problem)>,
+}
+
+pub type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
+impl<I> nom::error::ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            stack: vec![(input, Problem::Nom(kind))],
+        }
+    }
+
+    fn append(input: I, kind: ErrorKind, mut other: Self) -> Self {
+        other.stack.push((input, Problem::Nom(kind)));
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Self {
+            stack: vec![(input, Problem::Char(c))],
+        }
+    }
+}
+
+impl<I> ContextError<I> for Error<I> {
+    fn add_context(input: I, ctx: &'static str, mut other: Self) -> Self {
+        other.stack.push((input, Problem::Rule(Cow::Borrowed(ctx))));
+        other
+    }
+}
+
+impl<I, E: Display> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, _kind: ErrorKind, e: E) -> Self {
+        Self {
+            stack: vec![(input, Problem::External(format!("{e:#}")))],
+        }
+    }
+}
+
+/// Names the grammar rule parsed by `parser`, so that errors inside of it can refer to it
+///
+/// Unlike nom's `context`, the name doesn't have to be `'static`,
+/// which allows for rules like `mapping("seed-to-soil")`
+pub fn rule<I, O, P>(
+    name: impl Into<Cow<'static, str>>,
+    mut parser: P,
+) -> impl FnMut(I) -> IResult<I, O>
+where
+    I: Clone,
+    P: nom::Parser<I, O, Error<I>>,
+{
+    let name = name.into();
+    move |i: I| {
+        parser.parse(i.clone()).map_err(|e| {
+            e.map(|mut e| {
+                e.stack.push((i, Problem::Rule(name.clone())));
+                e
+            })
+        })
+    }
+}
+
+/// Parses a non-negative number into a `usize`, like nom's `u32` and friends do
+pub fn usize(i: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(i)
+}
+
+/// Like nom's `separated_list0`, but see [`separated_list1`]
+pub fn separated_list0<'a, O, O2>(
+    sep: impl nom::Parser<&'a str, O2, Error<&'a str>>,
+    elem: impl nom::Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list(0, sep, elem)
+}
+
+/// Like nom's `separated_list1`, except that an element failing after having consumed
+/// some input doesn't just end the list, but fails the whole thing
+///
+/// Otherwise, the error of e.g. a malformed line in the middle of the input would get lost,
+/// leaving only an unhelpful "expected end of input"
+pub fn separated_list1<'a, O, O2>(
+    sep: impl nom::Parser<&'a str, O2, Error<&'a str>>,
+    elem: impl nom::Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list(1, sep, elem)
+}
+
+fn separated_list<'a, O, O2>(
+    min: usize,
+    mut sep: impl nom::Parser<&'a str, O2, Error<&'a str>>,
+    mut elem: impl nom::Parser<&'a str, O, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    // whether the element failed right away, i.e. the list has simply ended
+    let at_start =
+        |e: &Error<&str>, i: &str| e.stack.first().map_or(i.len(), |(at, _)| at.len()) == i.len();
+
+    move |mut i| {
+        let mut res = vec![];
+        let mut before_sep = i;
+        loop {
+            match elem.parse(i) {
+                Ok((rest, o)) => {
+                    res.push(o);
+                    i = rest;
+                }
+                Err(nom::Err::Error(e)) if at_start(&e, i) && res.len() >= min => {
+                    return Ok((before_sep, res));
+                }
+                Err(nom::Err::Error(e)) if at_start(&e, i) => return Err(nom::Err::Error(e)),
+                Err(nom::Err::Error(e)) => return Err(nom::Err::Failure(e)),
+                Err(e) => return Err(e),
+            }
+            before_sep = i;
+            match sep.parse(i) {
+                Ok((rest, _)) => i = rest,
+                Err(nom::Err::Error(_)) => return Ok((i, res)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// A parser failure, located in the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based
+    pub line: usize,
+    /// 1-based, in characters
+    pub column: usize,
+    /// The line the failure happened on
+    pub snippet: String,
+    /// The innermost grammar rule that failed, if the parser named any
+    pub expected: Option<String>,
+    /// What exactly went wrong
+    pub problem: String,
+}
+
+impl ParseError {
+    /// Locates the failure that happened at the start of `rest`, a subslice of `input`
+    fn new(input: &str, rest: &str, expected: Option<String>, problem: String) -> Self {
+        let offset = rest.as_ptr() as usize - input.as_ptr() as usize;
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[line_start..].lines().next().unwrap_or("").to_string(),
+            expected,
+            problem,
+        }
+    }
+
+    fn from_error(input: &str, e: Error<&str>) -> Self {
+        let expected = e.stack.iter().find_map(|(_, p)| match p {
+            Problem::Rule(name) => Some(name.to_string()),
+            _ => None,
+        });
+        let Some((rest, problem)) = e.stack.into_iter().next() else {
+            return Self::new(input, input, expected, "invalid input".to_string());
+        };
+        let problem = match problem {
+            Problem::Rule(name) => format!("invalid `{name}`"),
+            Problem::Char(c) => format!("expected {c:?}"),
+            Problem::Nom(ErrorKind::Eof) => "unexpected end of input".to_string(),
+            Problem::Nom(kind) => format!("failed to parse {}", kind.description()),
+            Problem::External(e) => e,
+        };
+        Self::new(input, rest, expected, problem)
+    }
+
+    /// Moves the error down by `lines`, for when the input it was found in
+    /// doesn't start at the beginning of a file
+    #[must_use]
+    pub fn shifted(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        if let Some(expected) = &self.expected {
+            write!(f, "expected `{expected}`: ")?;
+        }
+        writeln!(f, "{}", self.problem)?;
+
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over the whole of `input`
+///
+/// Only whitespace may be left over once the parser is done
+pub fn parse_all<'a, O>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    input: &'a str,
+) -> Result<O, ParseError> {
+    match parser(input) {
+        Ok((rest, _)) if !rest.trim().is_empty() => Err(ParseError::new(
+            input,
+            rest,
+            None,
+            "expected end of input".to_string(),
+        )),
+        Ok((_, o)) => Ok(o),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::from_error(input, e)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            None,
+            "unexpected end of input".to_string(),
+        )),
+    }
+}
+
+/// Parses each line of `file` with `parse`, keeping the line numbers of the errors relative to `file`
+pub fn lines<'a, T>(
+    file: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError> + 'a,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    file.lines()
+        .enumerate()
+        .map(move |(i, line)| parse(line).map_err(|e| e.shifted(i)))
+}
+
+/// Parses a grid of characters, converting each of them with `cell`
+///
+/// The grid must have at least one cell, and all of its rows must be equally long
+pub fn grid<T, E: Display>(
+    s: &str,
+    mut cell: impl FnMut(char) -> Result<T, E>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut width = None;
+    let rows = s
+        .lines()
+        .map(|line| {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| {
+                    cell(c).map_err(|e| ParseError::new(s, &line[i..], None, format!("{e:#}")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            match *width.get_or_insert(row.len()) {
+                0 => Err(ParseError::new(s, line, None, "empty row".to_string())),
+                w if w != row.len() => Err(ParseError::new(
+                    s,
+                    &line[line.len()..],
+                    None,
+                    format!("expected a row of {w} cells, found {}", row.len()),
+                )),
+                _ => Ok(row),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if rows.is_empty() {
+        return Err(ParseError::new(s, s, None, "empty grid".to_string()));
+    }
+    Ok(rows)
+}
+
+/// Implements `FromStr` for `$obj`, using the nom parser `$parser`
+#[macro_export]
+macro_rules! impl_from_str_from_nom_parser {
+    ($parser:ident, $obj:ident) => {
+        impl ::core::str::FromStr for $obj {
+            type Err = $crate::parse::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::parse::parse_all($parser, s)
+            }
+        }
+    };
+}
+
+/// Implements `TryFrom<&str>` for `$obj<'a>`, which borrows from the input,
+/// using the nom parser `$parser`
+#[macro_export]
+macro_rules! impl_from_str_for_obj_with_lifetimes_from_nom_parser {
+    ($parser:ident, $obj:ident) => {
+        impl<'a> TryFrom<&'a str> for $obj<'a> {
+            type Error = $crate::parse::ParseError;
+            fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                $crate::parse::parse_all($parser, s)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, newline, u32},
+        sequence::{delimited, separated_pair},
+    };
+    use test_case::test_case;
+
+    use super::*;
+
+    fn pair(i: &str) -> IResult<&str, (u32, u32)> {
+        rule(
+            "pair",
+            delimited(char('('), separated_pair(u32, tag(", "), u32), char(')')),
+        )(i)
+    }
+
+    fn pairs(i: &str) -> IResult<&str, Vec<(u32, u32)>> {
+        separated_list1(newline, pair)(i)
+    }
+
+    #[test]
+    fn ok() {
+        assert_eq!(
+            parse_all(pairs, "(1, 2)\n(3, 4)\n"),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+    }
+
+    #[test]
+    fn location() {
+        let e = parse_all(pair, "(1, 2").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+        assert_eq!(e.expected.as_deref(), Some("pair"));
+        assert_eq!(e.problem, "expected ')'");
+        assert_eq!(
+            e.to_string(),
+            "line 1, column 6: expected `pair`: expected ')'\n  |\n1 | (1, 2\n  |      ^"
+        );
+    }
+
+    #[test]
+    fn trailing() {
+        let e = parse_all(pairs, "(1, 2)\n(3, 4)\n3, 4)\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 7));
+        assert_eq!(e.problem, "expected end of input");
+    }
+
+    #[test]
+    fn malformed_element() {
+        let e = parse_all(pairs, "(1, 2)\n(3 4)\n(5, 6)\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.expected.as_deref(), Some("pair"));
+    }
+
+    #[test]
+    fn owned_rule_name() {
+        let name = "seed-to-soil";
+        let e = parse_all(rule(format!("mapping({name:?})"), tag("x")), "y").unwrap_err();
+        assert_eq!(e.expected.as_deref(), Some(r#"mapping("seed-to-soil")"#));
+    }
+
+    #[test]
+    fn lines_keep_numbers() {
+        let errors: Vec<_> = lines("(1, 2)\n(3, 4\n", |l| parse_all(pair, l))
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (2, 6));
+    }
+
+    #[test]
+    fn grid_location() {
+        let e = grid("..\n.x", |c| match c {
+            '.' => Ok(()),
+            c => Err(format!("invalid cell: {c}")),
+        })
+        .unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.problem, "invalid cell: x");
+    }
+
+    #[test_case("" => (1, 1, "empty grid".to_string()); "empty")]
+    #[test_case("\n.." => (1, 1, "empty row".to_string()); "empty row")]
+    #[test_case("..\n.\n.." => (2, 2, "expected a row of 2 cells, found 1".to_string()); "short row")]
+    #[test_case("..\n...\n.." => (2, 4, "expected a row of 2 cells, found 3".to_string()); "long row")]
+    fn grid_shape(s: &str) -> (usize, usize, String) {
+        let e = grid(s, |_| Ok::<_, String>(())).unwrap_err();
+        (e.line, e.column, e.problem)
+    }
+}
@@ -0,0 +1,93 @@
+//! Reading the input bit by bit from a [`BufRead`], for inputs too big to be held in memory whole
+//!
+//! The days with line-oriented inputs solve them with `pN_from_reader(impl BufRead)`,
+//! which their `pN(&str)` is a wrapper around
+
+use std::io::BufRead;
+
+use anyhow::Context;
+
+use crate::parse::ParseError;
+
+/// Like [`crate::parse::lines`], but reads each line from `reader` only once it's needed
+pub fn lines<T>(
+    reader: impl BufRead,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> impl Iterator<Item = anyhow::Result<T>> {
+    reader
+        .lines()
+        .enumerate()
+        .map(move |(i, line)| Ok(parse(&line?).map_err(|e| e.shifted(i))?))
+}
+
+/// Reads the items of the `sep`-separated list in `reader` one by one
+///
+/// The line break that may end the input isn't part of the last item
+pub fn split(reader: impl BufRead, sep: u8) -> impl Iterator<Item = anyhow::Result<String>> {
+    let mut items = reader.split(sep).peekable();
+    core::iter::from_fn(move || {
+        let mut item = match items.next()? {
+            Ok(item) => item,
+            Err(e) => return Some(Err(e.into())),
+        };
+        if items.peek().is_none() {
+            if item.ends_with(b"\r\n") {
+                item.truncate(item.len() - 2);
+            } else if item.ends_with(b"\n") {
+                item.truncate(item.len() - 1);
+            }
+        }
+        Some(String::from_utf8(item).context("the input is not valid UTF-8"))
+    })
+}
+
+/// Generates a test checking the `pN_from_reader` functions of the calling crate
+/// against the answers recorded in its `inputs/answers.toml`, reading each input from its file
+#[macro_export]
+macro_rules! reader_answer_tests {
+    ($($part:ident => $from_reader:path),+ $(,)?) => {
+        #[test]
+        fn answers_from_reader() {
+            let inputs = ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+            let answers = $crate::answers::Answers::load(&inputs).unwrap();
+            for (input, part, expected) in answers.iter() {
+                let file = ::std::fs::File::open(inputs.join(input)).unwrap();
+                let reader = ::std::io::BufReader::new(file);
+                #[allow(unreachable_patterns)]
+                let actual = match part {
+                    $($crate::Part::$part => $crate::IntoAnswer::into_answer($from_reader(reader)),)+
+                    _ => continue,
+                };
+                let actual = actual.unwrap_or_else(|e| panic!("{input}, part {part}: {e:#}"));
+                assert_eq!(actual.to_string(), expected, "{input}, part {part}");
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("a,b,c" => vec!["a", "b", "c"]; "no line break")]
+    #[test_case("a,b,c\n" => vec!["a", "b", "c"]; "line break")]
+    #[test_case("a,b,c\r\n" => vec!["a", "b", "c"]; "crlf")]
+    #[test_case("a\n,b" => vec!["a\n", "b"]; "line break inside")]
+    fn split_items(input: &str) -> Vec<String> {
+        split(input.as_bytes(), b',').map(Result::unwrap).collect()
+    }
+
+    #[test]
+    fn lines_keep_numbers() {
+        let errors: Vec<_> = lines("1\n2\nx\n".as_bytes(), |l| {
+            crate::parse::parse_all(nom::character::complete::u32, l)
+        })
+        .filter_map(Result::err)
+        .collect();
+        assert_eq!(errors.len(), 1);
+        let e = errors[0].downcast_ref::<ParseError>().unwrap();
+        assert_eq!((e.line, e.column), (3, 1));
+    }
+}
@@ -0,0 +1,122 @@
+use core::fmt::Display;
+
+use anyhow::bail;
+
+use crate::{Answer, IntoAnswer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let res = match value {
+            1 => Self::One,
+            2 => Self::Two,
+            n => bail!("invalid part: {n}"),
+        };
+        Ok(res)
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
+}
+
+/// A solution to one part of a day's puzzle
+///
+/// Lets tooling (the runner, benchmarks etc.) treat all the days uniformly,
+/// regardless of what type each part's answer happens to have.
+/// Solutions are stateless, so they can be shared between threads
+pub trait Solution: Sync {
+    fn day(&self) -> u8;
+    fn part(&self) -> Part;
+    /// Parses the input into whatever model the part works on, without solving it yet
+    fn parse<'a>(&self, file: &'a str) -> anyhow::Result<Box<dyn Parsed + 'a>>;
+    fn solve(&self, file: &str) -> anyhow::Result<Answer> {
+        self.parse(file)?.solve()
+    }
+}
+
+/// An input parsed by [`Solution::parse`], ready to be solved
+pub trait Parsed {
+    fn solve(self: Box<Self>) -> anyhow::Result<Answer>;
+    /// The parsed input as JSON, to look at it from outside the day's crate
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> anyhow::Result<serde_json::Value>;
+}
+
+/// What a parsed input has to be, to be dumped with [`Parsed::to_json`]
+///
+/// With the `serde` feature, that's [`serde::Serialize`], which all the days' models
+/// implement when their own `serde` feature is on. Without it, anything goes
+#[cfg(feature = "serde")]
+pub trait Dump: serde::Serialize {}
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> Dump for T {}
+
+#[cfg(not(feature = "serde"))]
+pub trait Dump {}
+#[cfg(not(feature = "serde"))]
+impl<T> Dump for T {}
+
+struct WithSolver<T, R> {
+    input: T,
+    solve: fn(T) -> R,
+}
+
+impl<T: Dump, R: IntoAnswer> Parsed for WithSolver<T, R> {
+    fn solve(self: Box<Self>) -> anyhow::Result<Answer> {
+        (self.solve)(self.input).into_answer()
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_json(&self) -> anyhow::Result<serde_json::Value> {
+        Ok(serde_json::to_value(&self.input)?)
+    }
+}
+
+/// Pairs a parsed `input` with the function solving it
+pub fn parsed<'a, T: Dump + 'a, R: IntoAnswer + 'a>(
+    input: T,
+    solve: fn(T) -> R,
+) -> Box<dyn Parsed + 'a> {
+    Box::new(WithSolver { input, solve })
+}
+
+/// Implements [`Solution`] for `$solution`, the day's `DAY` and `$part`, parsing the input
+/// with `$parse` and then solving it with `$solve`, as [`parsed`] pairs them
+///
+/// `$parse` gets the input as `$file`, and can use `?` on the errors it runs into
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)] // `DAY` is the calling day's
+macro_rules! solution {
+    ($solution:ident, $part:expr, |$file:ident| $parse:expr, $solve:expr $(,)?) => {
+        impl $crate::Solution for $solution {
+            fn day(&self) -> u8 {
+                crate::DAY
+            }
+            fn part(&self) -> $crate::Part {
+                $part
+            }
+            fn parse<'a>(&self, $file: &'a str) -> ::anyhow::Result<Box<dyn $crate::Parsed + 'a>> {
+                Ok($crate::parsed($parse, $solve))
+            }
+        }
+    };
+}
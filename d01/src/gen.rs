@@ -0,0 +1,28 @@
+//! Calibration documents of `size` lines
+//!
+//! Every line has at least one digit, so that it's valid for both parts
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn line(rng: &mut StdRng) -> String {
+    let mut line = String::new();
+    for _ in 0..rng.gen_range(1..=8) {
+        match rng.gen_range(0..3) {
+            0 => line.push(char::from(b'0' + rng.gen_range(1..=9))),
+            1 => line.push_str(WORDS.choose(rng).unwrap()),
+            _ => line.push(char::from(rng.gen_range(b'a'..=b'z'))),
+        }
+    }
+    let digit = char::from(b'0' + rng.gen_range(1..=9));
+    let at = rng.gen_range(0..=line.len());
+    line.insert(at, digit);
+    line
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1)).map(|_| line(rng) + "\n").collect()
+}
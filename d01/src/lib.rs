@@ -1,6 +1,18 @@
 use core::marker::PhantomData;
 
+use common::Solution;
+
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
 struct WeirdNumber<P>(u32, PhantomData<P>);
 
+pub mod gen;
 pub mod p1;
 pub mod p2;
+
+const DAY: u8 = 1;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
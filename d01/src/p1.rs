@@ -1,11 +1,13 @@
 use core::{marker::PhantomData, str::FromStr};
+use std::io::BufRead;
 
 use anyhow::Context;
+use common::Part;
 use itertools::Itertools;
 
 use crate::WeirdNumber;
 
-struct P1;
+pub struct P1;
 impl FromStr for WeirdNumber<P1> {
     type Err = anyhow::Error;
 
@@ -24,21 +26,22 @@ impl FromStr for WeirdNumber<P1> {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    file.lines()
-        .map(WeirdNumber::<P1>::from_str)
-        .fold_ok(0, |acc, WeirdNumber(n, _)| acc + n)
+    p1_from_reader(file.as_bytes())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example1.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 142)]
-    #[test_case(REAL => 55712)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    reader
+        .lines()
+        .map(|line| Ok(WeirdNumber::<P1>::from_str(&line?)?.0))
+        .sum()
+}
+
+fn parse(file: &str) -> anyhow::Result<Vec<WeirdNumber<P1>>> {
+    file.lines().map(WeirdNumber::<P1>::from_str).try_collect()
 }
+
+fn solve(numbers: Vec<WeirdNumber<P1>>) -> u32 {
+    numbers.into_iter().map(|WeirdNumber(n, _)| n).sum()
+}
+
+common::solution!(P1, Part::One, |file| parse(file)?, solve);
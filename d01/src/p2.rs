@@ -1,6 +1,8 @@
 use core::{marker::PhantomData, str::FromStr};
+use std::io::BufRead;
 
 use anyhow::Context;
+use common::Part;
 use itertools::Itertools;
 
 use crate::WeirdNumber;
@@ -9,7 +11,7 @@ const DIGIT_STRS: [&str; 9] = [
     "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-struct P2;
+pub struct P2;
 impl FromStr for WeirdNumber<P2> {
     type Err = anyhow::Error;
 
@@ -48,21 +50,22 @@ impl FromStr for WeirdNumber<P2> {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<u32> {
-    file.lines()
-        .map(WeirdNumber::<P2>::from_str)
-        .fold_ok(0, |acc, WeirdNumber(n, _)| acc + n)
+    p2_from_reader(file.as_bytes())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example2.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    reader
+        .lines()
+        .map(|line| Ok(WeirdNumber::<P2>::from_str(&line?)?.0))
+        .sum()
+}
 
-    #[test_case(EXAMPLE => 281)]
-    #[test_case(REAL => 55413)]
-    fn test_p2(inp: &str) -> u32 {
-        p2(inp).unwrap()
-    }
+fn parse(file: &str) -> anyhow::Result<Vec<WeirdNumber<P2>>> {
+    file.lines().map(WeirdNumber::<P2>::from_str).try_collect()
 }
+
+fn solve(numbers: Vec<WeirdNumber<P2>>) -> u32 {
+    numbers.into_iter().map(|WeirdNumber(n, _)| n).sum()
+}
+
+common::solution!(P2, Part::Two, |file| parse(file)?, solve);
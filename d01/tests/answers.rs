@@ -0,0 +1,5 @@
+common::answer_tests!(d01::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d01::p1::p1_from_reader,
+    Two => d01::p2::p2_from_reader,
+);
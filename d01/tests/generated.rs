@@ -0,0 +1 @@
+common::generated_input_tests!(d01::gen::input, d01::SOLUTIONS, [1, 5, 20]);
@@ -1,17 +1,20 @@
 use core::str::FromStr;
 
 use anyhow::{bail, ensure, Context};
-use libaoc::impl_from_str_from_nom_parser;
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, IResult},
+};
 use nom::{
     bytes::complete::{tag, take_till},
     character::complete::u32,
     combinator::{map, map_res},
     multi::separated_list1,
     sequence::{preceded, separated_pair},
-    IResult,
 };
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Set {
     pub(crate) red: u32,
     pub(crate) green: u32,
@@ -19,6 +22,7 @@ pub(crate) struct Set {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Game {
     pub(crate) id: u32,
     pub(crate) sets: Vec<Set>,
@@ -53,17 +57,20 @@ impl FromStr for Set {
 }
 
 fn set(i: &str) -> IResult<&str, Set> {
-    map_res(take_till(|c| c == ';'), Set::from_str)(i)
+    rule("set", map_res(take_till(|c| c == ';'), Set::from_str))(i)
 }
 
 fn sets(i: &str) -> IResult<&str, Vec<Set>> {
-    separated_list1(tag("; "), set)(i)
+    rule("sets", separated_list1(tag("; "), set))(i)
 }
 
 fn game(i: &str) -> IResult<&str, Game> {
-    map(
-        separated_pair(preceded(tag("Game "), u32), tag(": "), sets),
-        |(id, sets)| Game { id, sets },
+    rule(
+        "game",
+        map(
+            separated_pair(preceded(tag("Game "), u32), tag(": "), sets),
+            |(id, sets)| Game { id, sets },
+        ),
     )(i)
 }
 
@@ -0,0 +1,23 @@
+//! Records of `size` games, each revealing a handful of cube sets
+
+use common::gen::{Rng, SliceRandom, StdRng};
+use itertools::Itertools;
+
+fn cube_set(rng: &mut StdRng) -> String {
+    let mut colours = ["red", "green", "blue"];
+    colours.shuffle(rng);
+    colours[..rng.gen_range(1..=3)]
+        .iter()
+        .map(|colour| format!("{} {colour}", rng.gen_range(1..=20)))
+        .join(", ")
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            let sets = (0..rng.gen_range(1..=6)).map(|_| cube_set(rng)).join("; ");
+            format!("Game {id}: {sets}\n")
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
@@ -1,5 +1,7 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
+use common::Part;
 use itertools::Itertools;
 
 use crate::game::Game;
@@ -17,27 +19,28 @@ impl Game {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let games: Box<_> = file.lines().map(Game::from_str).try_collect()?;
+    p1_from_reader(file.as_bytes())
+}
+
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    common::read::lines(reader, Game::from_str)
+        .filter_ok(Game::is_possible)
+        .map_ok(|g| g.id)
+        .sum()
+}
+
+fn parse(file: &str) -> anyhow::Result<Box<[Game]>> {
+    Ok(common::parse::lines(file, Game::from_str).try_collect()?)
+}
 
+fn solve(games: Box<[Game]>) -> u32 {
     // TODO: change this to `games.into_iter()` in Rust 2024 Edition
-    let res = <Box<_> as IntoIterator>::into_iter(games)
+    <Box<_> as IntoIterator>::into_iter(games)
         .filter(Game::is_possible)
         .map(|g| g.id)
-        .sum();
-
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 8)]
-    #[test_case(REAL => 2239)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
-}
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| parse(file)?, solve);
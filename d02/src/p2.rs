@@ -1,5 +1,7 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
+use common::Part;
 use itertools::Itertools;
 
 use crate::game::Game;
@@ -15,27 +17,23 @@ impl Game {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<u32> {
-    let games: Box<_> = file.lines().map(Game::from_str).try_collect()?;
+    p2_from_reader(file.as_bytes())
+}
 
-    let res = games
-        .iter()
-        .map(Game::power)
-        .inspect(|p| eprintln!("{p}"))
-        .sum();
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    common::read::lines(reader, Game::from_str)
+        .map_ok(|g| g.power())
+        .sum()
+}
 
-    Ok(res)
+fn parse(file: &str) -> anyhow::Result<Box<[Game]>> {
+    Ok(common::parse::lines(file, Game::from_str).try_collect()?)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 2286)]
-    #[test_case(REAL => 83435)]
-    fn test_p2(inp: &str) -> u32 {
-        p2(inp).unwrap()
-    }
+fn solve(games: &[Game]) -> u32 {
+    games.iter().map(Game::power).sum()
 }
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| parse(file)?, |games| solve(&games));
@@ -0,0 +1,5 @@
+common::answer_tests!(d02::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d02::p1::p1_from_reader,
+    Two => d02::p2::p2_from_reader,
+);
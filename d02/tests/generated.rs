@@ -0,0 +1 @@
+common::generated_input_tests!(d02::gen::input, d02::SOLUTIONS, [1, 5, 20]);
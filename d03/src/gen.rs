@@ -0,0 +1,31 @@
+//! Engine schematics of `size`×`size` characters
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+fn row(rng: &mut StdRng, width: usize) -> String {
+    let mut row = vec![b'.'; width];
+    let mut x = rng.gen_range(0..4);
+    while x < width {
+        if rng.gen_bool(0.2) {
+            row[x] = *SYMBOLS.choose(rng).unwrap();
+            x += 1;
+        } else {
+            let len = rng.gen_range(1..=3).min(width - x);
+            row[x] = rng.gen_range(b'1'..=b'9');
+            for cell in &mut row[x + 1..x + len] {
+                *cell = rng.gen_range(b'0'..=b'9');
+            }
+            x += len;
+        }
+        // leave some room, so that not all numbers are next to symbols
+        x += rng.gen_range(1..6);
+    }
+    String::from_utf8(row).unwrap()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size).map(|_| row(rng, size) + "\n").collect()
+}
@@ -1,3 +1,10 @@
+use common::Solution;
+
+pub mod gen;
 pub(crate) mod number;
 pub mod p1;
 pub mod p2;
+
+const DAY: u8 = 3;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,17 +1,54 @@
+use anyhow::Context;
+use grid::{Grid, Pos};
+use libaoc::points::Point2D;
+
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Number {
     pub(crate) value: u32,
-    pub(crate) start_pos: (usize, usize),
-    pub(crate) len: u32,
+    #[cfg_attr(feature = "serde", serde(with = "grid::serialize::pos"))]
+    pub(crate) start_pos: Pos,
+    pub(crate) len: usize,
+}
+
+impl Number {
+    /// The positions of the number's digits
+    pub(crate) fn positions(&self) -> impl Iterator<Item = Pos> {
+        let Point2D(start_x, y) = self.start_pos;
+        (start_x..start_x + self.len).map(move |x| Point2D(x, y))
+    }
 }
 
-pub(crate) fn parse_numbers(s: &str) -> Vec<Number> {
-    let width = s.lines().next().unwrap().len();
+/// Parses the engine schematic `s`, which must be a non-empty rectangle of ASCII characters
+pub(crate) fn schematic(s: &str) -> anyhow::Result<Grid<char>> {
+    let schematic = Grid::parse(s, |c| {
+        if c.is_ascii() {
+            Ok(c)
+        } else {
+            Err(format!("non-ASCII character: {c}"))
+        }
+    })?;
+    Ok(schematic)
+}
 
-    s.lines()
-        .enumerate()
-        .flat_map(|(y, line)| line.char_indices().map(move |(x, c)| ((x, y), c)))
-        .fold((vec![], None), |(mut acc, mut curr_number), ((x, y), c)| {
+/// The engine schematic, with the numbers written on it
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Schematic {
+    pub(crate) grid: Grid<char>,
+    pub(crate) numbers: Vec<Number>,
+}
+
+pub(crate) fn parse(s: &str) -> anyhow::Result<Schematic> {
+    let grid = schematic(s)?;
+    let numbers = parse_numbers(&grid)?;
+    Ok(Schematic { grid, numbers })
+}
+
+pub(crate) fn parse_numbers(schematic: &Grid<char>) -> anyhow::Result<Vec<Number>> {
+    let mut numbers = vec![];
+    for (y, row) in schematic.rows().enumerate() {
+        let mut curr_number: Option<Number> = None;
+        for (x, c) in row.iter().enumerate() {
             match (&mut curr_number, c.to_digit(10)) {
                 (None, None) => {
                     // no current number and no new one encountered
@@ -21,29 +58,51 @@ pub(crate) fn parse_numbers(s: &str) -> Vec<Number> {
                     // a new number encountered!
                     curr_number = Some(Number {
                         value: n,
-                        start_pos: (x, y),
+                        start_pos: Point2D(x, y),
                         len: 1,
                     });
                 }
                 (Some(num), Some(n)) => {
                     // continue building up the number
-                    num.value = 10 * num.value + n;
+                    num.value = num
+                        .value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(n))
+                        .with_context(|| {
+                            let Point2D(x, y) = num.start_pos;
+                            format!("number at line {}, column {} is too large", y + 1, x + 1)
+                        })?;
                     num.len += 1;
-
-                    // if this is the last character of the line,
-                    // finish up the number immediately
-                    if x == width - 1 {
-                        acc.push(*num);
-                        curr_number = None;
-                    }
                 }
                 (Some(num), None) => {
                     // a non-digit encountered - finish up the current number!
-                    acc.push(*num);
+                    numbers.push(*num);
                     curr_number = None;
                 }
             }
-            (acc, curr_number)
-        })
-        .0
+        }
+        // the end of the line finishes up the number, too
+        numbers.extend(curr_number);
+    }
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("" => "line 1, column 1: empty grid"; "empty")]
+    #[test_case("467..\n...*" => "line 2, column 5: expected a row of 5 cells, found 4"; "ragged")]
+    #[test_case("..\n.é" => "line 2, column 2: non-ASCII character: é"; "non-ascii")]
+    fn invalid_schematic(s: &str) -> String {
+        let e = schematic(s).unwrap_err().to_string();
+        e.lines().next().unwrap().to_string()
+    }
+
+    #[test]
+    fn number_too_large() {
+        let e = parse_numbers(&schematic("..99999999999*").unwrap()).unwrap_err();
+        assert_eq!(e.to_string(), "number at line 1, column 3 is too large");
+    }
 }
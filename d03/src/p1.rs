@@ -1,73 +1,31 @@
 use std::collections::HashSet;
 
-use crate::number::{parse_numbers, Number};
+use common::Part;
+use grid::Pos;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Symbol {
-    pos: (usize, usize),
-}
+use crate::number::{parse, Schematic};
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let height = file.lines().count();
-    let width = file.lines().next().unwrap().len();
+    Ok(solve(&parse(file)?))
+}
 
-    let symbols: HashSet<_> = file
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| line.char_indices().map(move |(x, c)| ((x, y), c)))
-        .filter(|(_, c)| !c.is_ascii_digit() && *c != '.')
-        .flat_map(|((x, y), _)| {
-            std::iter::empty()
-                .chain((x > 0).then(|| Symbol { pos: (x - 1, y) }))
-                .chain((y > 0).then(|| Symbol { pos: (x, y - 1) }))
-                .chain((x < width - 1).then_some(Symbol { pos: (x + 1, y) }))
-                .chain((y < height - 1).then_some(Symbol { pos: (x, y + 1) }))
-                .chain((x > 0 && y > 0).then(|| Symbol {
-                    pos: (x - 1, y - 1),
-                }))
-                .chain((x > 0 && y < width - 1).then(|| Symbol {
-                    pos: (x - 1, y + 1),
-                }))
-                .chain((x < width - 1 && y > 0).then(|| Symbol {
-                    pos: (x + 1, y - 1),
-                }))
-                .chain((x < width - 1 && y < height - 1).then_some(Symbol {
-                    pos: (x + 1, y + 1),
-                }))
-        })
-        .collect();
+fn solve(schematic: &Schematic) -> u32 {
+    let Schematic { grid, numbers } = schematic;
 
-    let numbers = parse_numbers(file);
+    // the positions adjacent to a symbol, even diagonally
+    let next_to_symbols: HashSet<Pos> = grid
+        .iter()
+        .filter(|(_, c)| !c.is_ascii_digit() && **c != '.')
+        .flat_map(|(pos, _)| grid.neighbours8(pos))
+        .collect();
 
-    let res = numbers
+    numbers
         .iter()
-        .filter(
-            |&&Number {
-                 start_pos: (start_x, start_y),
-                 len,
-                 ..
-             }| {
-                (start_x..start_x + len as usize)
-                    .map(|x| Symbol { pos: (x, start_y) })
-                    .any(|s| symbols.contains(&s))
-            },
-        )
+        .filter(|n| n.positions().any(|pos| next_to_symbols.contains(&pos)))
         .map(|n| n.value)
-        .sum();
-
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P1;
 
-    #[test_case(EXAMPLE => 4361)]
-    #[test_case(REAL => 536_576)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
-}
+common::solution!(P1, Part::One, |file| parse(file)?, |s| solve(&s));
@@ -0,0 +1 @@
+common::generated_input_tests!(d03::gen::input, d03::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,34 @@
+//! Piles of `size` scratchcards
+//!
+//! No card wins copies of cards past the end of the pile
+
+use common::gen::{Rng, SliceRandom, StdRng};
+use itertools::Itertools;
+
+const N_WINNING: usize = 5;
+const N_YOURS: usize = 8;
+
+fn numbers(numbers: &[u32]) -> String {
+    numbers.iter().map(|n| format!("{n:2}")).join(" ")
+}
+
+fn card(rng: &mut StdRng, id: usize, max_matches: usize) -> String {
+    let mut pool: Vec<u32> = (1..100).collect();
+    pool.shuffle(rng);
+    let (winning, rest) = pool.split_at(N_WINNING);
+
+    let n_matches = rng.gen_range(0..=max_matches.min(N_WINNING));
+    let mut yours: Vec<u32> = winning[..n_matches]
+        .iter()
+        .chain(&rest[..N_YOURS - n_matches])
+        .copied()
+        .collect();
+    yours.shuffle(rng);
+
+    format!("Card {id:3}: {} | {}\n", numbers(winning), numbers(&yours))
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    (1..=size).map(|id| card(rng, id, size - id)).collect()
+}
@@ -1,3 +1,10 @@
+use common::Solution;
+
 pub(crate) mod card;
+pub mod gen;
 pub mod p1;
 pub mod p2;
+
+const DAY: u8 = 4;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,6 +1,8 @@
 use core::str::FromStr;
 use std::collections::HashSet;
+use std::io::BufRead;
 
+use common::Part;
 use itertools::Itertools;
 
 use crate::card::Card;
@@ -17,23 +19,23 @@ impl Card {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let cards: Vec<_> = file.lines().map(Card::from_str).try_collect()?;
+    p1_from_reader(file.as_bytes())
+}
 
-    let res = cards.iter().map(Card::worth).sum();
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    common::read::lines(reader, Card::from_str)
+        .map_ok(|c| c.worth())
+        .sum()
+}
 
-    Ok(res)
+fn parse(file: &str) -> anyhow::Result<Vec<Card>> {
+    Ok(common::parse::lines(file, Card::from_str).try_collect()?)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 13)]
-    #[test_case(REAL => 20829)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
+fn solve(cards: &[Card]) -> u32 {
+    cards.iter().map(Card::worth).sum()
 }
+
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| parse(file)?, |cards| solve(&cards));
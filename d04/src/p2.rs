@@ -1,42 +1,105 @@
 use core::str::FromStr;
 use std::collections::HashSet;
+use std::io::BufRead;
 
+use anyhow::Context;
+use common::Part;
 use itertools::Itertools;
 
 use crate::card::Card;
 
+impl Card {
+    fn n_matches(&self) -> usize {
+        HashSet::intersection(&self.your_numbers, &self.winning_numbers).count()
+    }
+}
+
 pub fn p2(file: &str) -> anyhow::Result<u32> {
-    let cards: Box<_> = file.lines().map(Card::from_str).try_collect()?;
+    p2_from_reader(file.as_bytes())
+}
+
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    let worths: Vec<_> = common::read::lines(reader, Card::from_str)
+        .map_ok(|c| c.n_matches())
+        .try_collect()?;
+    total_cards(&worths)
+}
+
+fn parse(file: &str) -> anyhow::Result<Box<[Card]>> {
+    Ok(common::parse::lines(file, Card::from_str).try_collect()?)
+}
+
+fn solve(cards: &[Card]) -> anyhow::Result<u32> {
+    let worths: Box<_> = cards.iter().map(Card::n_matches).collect();
 
-    let worths: Box<_> = cards
-        .iter()
-        .map(|c| HashSet::intersection(&c.your_numbers, &c.winning_numbers).count())
-        .collect();
+    total_cards(&worths)
+}
 
+/// The number of cards you end up with, given how many matching numbers each card has
+fn total_cards(worths: &[usize]) -> anyhow::Result<u32> {
     // how many cards you'll get in total after beginning with this one card
-    let mut actual_worths = vec![1u32; cards.len()];
-    for i in (0..cards.len()).rev() {
+    let mut actual_worths = vec![1u32; worths.len()];
+    for i in (0..worths.len()).rev() {
         let add_worth = match worths[i] {
             0 => 0,
-            n => actual_worths[i + 1..][..n].iter().sum(),
+            n => actual_worths
+                .get(i + 1..i + 1 + n)
+                .with_context(|| {
+                    format!(
+                        "card {} wins copies of cards past the end of the table",
+                        i + 1
+                    )
+                })?
+                .iter()
+                .sum(),
         };
         actual_worths[i] += add_worth;
     }
 
-    let res = actual_worths.into_iter().sum();
-    Ok(res)
+    Ok(actual_worths.into_iter().sum())
 }
 
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| parse(file)?, |cards| solve(&cards));
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
     use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
 
-    #[test_case(EXAMPLE => 30)]
-    #[test_case(REAL => 12_648_035)]
-    fn test_p2(inp: &str) -> u32 {
-        p2(inp).unwrap()
+    #[test_case("Card 1: 1 2 | 1 2 3\nCard 2: 4 | 5" => "card 1 wins copies of cards past the end of the table"; "past the end")]
+    fn invalid(input: &str) -> String {
+        p2(input).unwrap_err().to_string()
+    }
+
+    /// Scratches every card one by one, including all the won copies
+    fn scratch_all(worths: &[usize]) -> u32 {
+        let mut to_scratch: Vec<usize> = (0..worths.len()).collect();
+        let mut n_scratched = 0;
+        while let Some(i) = to_scratch.pop() {
+            n_scratched += 1;
+            to_scratch.extend(i + 1..i + 1 + worths[i]);
+        }
+        n_scratched
+    }
+
+    /// Numbers of matches on cards, none of which win copies of cards past the end of the table
+    fn worths() -> impl Strategy<Value = Vec<usize>> {
+        prop::collection::vec(0..5usize, 0..12).prop_map(|mut worths| {
+            let len = worths.len();
+            for (i, worth) in worths.iter_mut().enumerate() {
+                *worth = (*worth).min(len - 1 - i);
+            }
+            worths
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn total_cards_matches_scratching_every_card(worths in worths()) {
+            prop_assert_eq!(total_cards(&worths).unwrap(), scratch_all(&worths));
+        }
     }
 }
@@ -0,0 +1,5 @@
+common::answer_tests!(d04::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d04::p1::p1_from_reader,
+    Two => d04::p2::p2_from_reader,
+);
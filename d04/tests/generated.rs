@@ -0,0 +1 @@
+common::generated_input_tests!(d04::gen::input, d04::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,111 @@
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, newline, u32},
+    combinator::map,
+    sequence::{preceded, tuple},
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Almanac {
+    pub(crate) seeds: Vec<u32>,
+    pub(crate) seed2soil: Mapping,
+    pub(crate) soil2fertilizer: Mapping,
+    pub(crate) fertilizer2water: Mapping,
+    pub(crate) water2light: Mapping,
+    pub(crate) light2temperature: Mapping,
+    pub(crate) temperature2humidity: Mapping,
+    pub(crate) humidity2location: Mapping,
+}
+
+fn seeds(i: &str) -> IResult<&str, Vec<u32>> {
+    rule(
+        "seeds",
+        preceded(tag("seeds: "), separated_list1(char(' '), u32)),
+    )(i)
+}
+
+fn transformation(i: &str) -> IResult<&str, Transformation> {
+    rule(
+        "transformation",
+        map(
+            tuple((u32, preceded(char(' '), u32), preceded(char(' '), u32))),
+            |(dst_start, src_start, len)| Transformation {
+                src_start,
+                len,
+                dst_start,
+            },
+        ),
+    )(i)
+}
+
+fn mapping(name: &str) -> impl FnMut(&str) -> IResult<&str, Mapping> + '_ {
+    move |i: &str| {
+        rule(
+            format!("mapping({name:?})"),
+            map(
+                preceded(
+                    tuple((tag(name), tag(" map:"), newline)),
+                    separated_list1(newline, transformation),
+                ),
+                Mapping,
+            ),
+        )(i)
+    }
+}
+
+fn newline2(i: &str) -> IResult<&str, (char, char)> {
+    rule("newline2", tuple((newline, newline)))(i)
+}
+
+fn almanac(i: &str) -> IResult<&str, Almanac> {
+    rule(
+        "almanac",
+        map(
+            tuple((
+                seeds,
+                preceded(newline2, mapping("seed-to-soil")),
+                preceded(newline2, mapping("soil-to-fertilizer")),
+                preceded(newline2, mapping("fertilizer-to-water")),
+                preceded(newline2, mapping("water-to-light")),
+                preceded(newline2, mapping("light-to-temperature")),
+                preceded(newline2, mapping("temperature-to-humidity")),
+                preceded(newline2, mapping("humidity-to-location")),
+            )),
+            |(
+                seeds,
+                seed2soil,
+                soil2fertilizer,
+                fertilizer2water,
+                water2light,
+                light2temperature,
+                temperature2humidity,
+                humidity2location,
+            )| Almanac {
+                seeds,
+                seed2soil,
+                soil2fertilizer,
+                fertilizer2water,
+                water2light,
+                light2temperature,
+                temperature2humidity,
+                humidity2location,
+            },
+        ),
+    )(i)
+}
+
+impl_from_str_from_nom_parser!(almanac, Almanac);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Mapping(pub(crate) Vec<Transformation>);
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Transformation {
+    pub(crate) src_start: u32,
+    pub(crate) len: u32,
+    pub(crate) dst_start: u32,
+}
@@ -0,0 +1,77 @@
+//! Almanacs with `size` seed ranges and up to `size` ranges in each map
+//!
+//! The seed ranges overlap each other, and the ranges of the maps cut across them.
+//! The source ranges within one map never overlap though, as that would make the mapping ambiguous
+
+use core::fmt::Write;
+use std::collections::BTreeSet;
+
+use common::gen::{Rng, StdRng};
+
+const MAX_VALUE: u32 = 1_000_000;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// `n` disjoint ranges within `0..MAX_VALUE`, as `(start, len)`
+fn disjoint_ranges(rng: &mut StdRng, n: usize) -> Vec<(u32, u32)> {
+    let mut bounds = BTreeSet::new();
+    while bounds.len() < 2 * n {
+        bounds.insert(rng.gen_range(0..MAX_VALUE));
+    }
+    let bounds: Vec<u32> = bounds.into_iter().collect();
+    bounds
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1] - pair[0]))
+        .collect()
+}
+
+fn seeds(rng: &mut StdRng, size: usize) -> String {
+    let mut ranges: Vec<(u32, u32)> = vec![];
+    for _ in 0..size {
+        let range = match ranges.last() {
+            // start inside the previous range
+            Some(&(start, len)) if rng.gen_bool(0.5) => (
+                start + rng.gen_range(0..len),
+                rng.gen_range(1..MAX_VALUE / 10),
+            ),
+            _ => (
+                rng.gen_range(0..MAX_VALUE / 2),
+                rng.gen_range(1..MAX_VALUE / 10),
+            ),
+        };
+        ranges.push(range);
+    }
+    let seeds: Vec<String> = ranges
+        .iter()
+        .map(|(start, len)| format!("{start} {len}"))
+        .collect();
+    format!("seeds: {}\n", seeds.join(" "))
+}
+
+fn map(rng: &mut StdRng, name: &str, size: usize) -> String {
+    let mut map = format!("{name} map:\n");
+    let n_ranges = rng.gen_range(1..=size);
+    for (src_start, len) in disjoint_ranges(rng, n_ranges) {
+        let dst_start = rng.gen_range(0..MAX_VALUE);
+        writeln!(map, "{dst_start} {src_start} {len}").unwrap();
+    }
+    map
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let mut almanac = seeds(rng, size);
+    for name in MAPS {
+        almanac += "\n";
+        almanac += &map(rng, name, size);
+    }
+    almanac
+}
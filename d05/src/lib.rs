@@ -1,2 +1,10 @@
+use common::Solution;
+
+pub(crate) mod almanac;
+pub mod gen;
 pub mod p1;
 pub mod p2;
+
+const DAY: u8 = 5;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
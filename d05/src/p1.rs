@@ -1,93 +1,9 @@
 use core::str::FromStr;
 
 use anyhow::Context;
-use libaoc::impl_from_str_from_nom_parser;
-use nom::{
-    bytes::complete::tag,
-    character::complete::{char, newline, u32},
-    combinator::map,
-    multi::separated_list1,
-    sequence::{preceded, tuple},
-    IResult,
-};
+use common::Part;
 
-struct Almanac {
-    seeds: Vec<u32>,
-    seed2soil: Mapping,
-    soil2fertilizer: Mapping,
-    fertilizer2water: Mapping,
-    water2light: Mapping,
-    light2temperature: Mapping,
-    temperature2humidity: Mapping,
-    humidity2location: Mapping,
-}
-
-fn seeds(i: &str) -> IResult<&str, Vec<u32>> {
-    preceded(tag("seeds: "), separated_list1(char(' '), u32))(i)
-}
-
-fn transformation(i: &str) -> IResult<&str, Transformation> {
-    map(
-        tuple((u32, preceded(char(' '), u32), preceded(char(' '), u32))),
-        |(dst_start, src_start, len)| Transformation {
-            src_start,
-            len,
-            dst_start,
-        },
-    )(i)
-}
-
-fn mapping(name: &str) -> impl FnMut(&str) -> IResult<&str, Mapping> + '_ {
-    move |i: &str| {
-        map(
-            preceded(
-                tuple((tag(name), tag(" map:"), newline)),
-                separated_list1(newline, transformation),
-            ),
-            Mapping,
-        )(i)
-    }
-}
-
-fn newline2(i: &str) -> IResult<&str, (char, char)> {
-    tuple((newline, newline))(i)
-}
-
-fn almanac(i: &str) -> IResult<&str, Almanac> {
-    map(
-        tuple((
-            seeds,
-            preceded(newline2, mapping("seed-to-soil")),
-            preceded(newline2, mapping("soil-to-fertilizer")),
-            preceded(newline2, mapping("fertilizer-to-water")),
-            preceded(newline2, mapping("water-to-light")),
-            preceded(newline2, mapping("light-to-temperature")),
-            preceded(newline2, mapping("temperature-to-humidity")),
-            preceded(newline2, mapping("humidity-to-location")),
-        )),
-        |(
-            seeds,
-            seed2soil,
-            soil2fertilizer,
-            fertilizer2water,
-            water2light,
-            light2temperature,
-            temperature2humidity,
-            humidity2location,
-        )| Almanac {
-            seeds,
-            seed2soil,
-            soil2fertilizer,
-            fertilizer2water,
-            water2light,
-            light2temperature,
-            temperature2humidity,
-            humidity2location,
-        },
-    )(i)
-}
-
-impl_from_str_from_nom_parser!(almanac, Almanac);
+use crate::almanac::{Almanac, Mapping};
 
 impl Almanac {
     fn seed2location(&self, seed: u32) -> u32 {
@@ -101,14 +17,6 @@ impl Almanac {
     }
 }
 
-struct Mapping(Vec<Transformation>);
-
-struct Transformation {
-    src_start: u32,
-    len: u32,
-    dst_start: u32,
-}
-
 trait MapWith {
     fn map_with(self, m: &Mapping) -> Self;
 }
@@ -129,8 +37,10 @@ impl MapWith for u32 {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let a = Almanac::from_str(file)?;
+    solve(&Almanac::from_str(file)?)
+}
 
+fn solve(a: &Almanac) -> anyhow::Result<u32> {
     a.seeds
         .iter()
         .map(|s| a.seed2location(*s))
@@ -138,16 +48,8 @@ pub fn p1(file: &str) -> anyhow::Result<u32> {
         .context("no seeds")
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P1;
 
-    #[test_case(EXAMPLE => 35)]
-    #[test_case(REAL => 621_354_867)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
-}
+common::solution!(P1, Part::One, |file| Almanac::from_str(file)?, |a| {
+    solve(&a)
+});
@@ -1,17 +1,145 @@
-pub fn p2(_file: &str) -> anyhow::Result<u32> {
-    todo!()
+use core::{ops::Range, str::FromStr};
+
+use anyhow::Context;
+use common::Part;
+
+use crate::almanac::{Almanac, Mapping, Transformation};
+
+impl Transformation {
+    fn src(&self) -> Range<u64> {
+        u64::from(self.src_start)..u64::from(self.src_start) + u64::from(self.len)
+    }
+
+    fn apply(&self, r: Range<u64>) -> Range<u64> {
+        let offset = |n: u64| n - u64::from(self.src_start) + u64::from(self.dst_start);
+        offset(r.start)..offset(r.end)
+    }
+}
+
+impl Mapping {
+    /// Maps whole ranges at once, splitting them where the transformations start and end
+    fn map_ranges(&self, ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut transformations: Vec<_> = self.0.iter().collect();
+        transformations.sort_unstable_by_key(|t| t.src_start);
+
+        let mut mapped = vec![];
+        for r in ranges {
+            let mut start = r.start;
+            for t in &transformations {
+                let src = t.src();
+                if src.end <= start {
+                    continue;
+                }
+                if src.start >= r.end {
+                    break;
+                }
+                // the part before the transformation is left as is
+                if start < src.start {
+                    mapped.push(start..src.start);
+                    start = src.start;
+                }
+                let end = src.end.min(r.end);
+                mapped.push(t.apply(start..end));
+                start = end;
+            }
+            if start < r.end {
+                mapped.push(start..r.end);
+            }
+        }
+        mapped
+    }
+}
+
+impl Almanac {
+    /// The seeds, read as pairs of the start and the length of a range
+    fn seed_ranges(&self) -> anyhow::Result<Vec<Range<u64>>> {
+        self.seeds
+            .chunks(2)
+            .map(|pair| match *pair {
+                [start, len] => Ok(u64::from(start)..u64::from(start) + u64::from(len)),
+                _ => anyhow::bail!("a seed range without a length"),
+            })
+            .collect()
+    }
+
+    fn seed_ranges2location_ranges(&self) -> anyhow::Result<Vec<Range<u64>>> {
+        let mappings = [
+            &self.seed2soil,
+            &self.soil2fertilizer,
+            &self.fertilizer2water,
+            &self.water2light,
+            &self.light2temperature,
+            &self.temperature2humidity,
+            &self.humidity2location,
+        ];
+        Ok(mappings
+            .into_iter()
+            .fold(self.seed_ranges()?, |ranges, m| m.map_ranges(ranges)))
+    }
+}
+
+pub fn p2(file: &str) -> anyhow::Result<u64> {
+    solve(&Almanac::from_str(file)?)
 }
 
+fn solve(a: &Almanac) -> anyhow::Result<u64> {
+    a.seed_ranges2location_ranges()?
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .map(|r| r.start)
+        .min()
+        .context("no seeds")
+}
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Almanac::from_str(file)?, |a| {
+    solve(&a)
+});
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => ignore)]
-    #[test_case(REAL => ignore)]
-    fn test_p2(inp: &str) -> u32 {
-        p2(inp).unwrap()
+
+    fn mapping(transformations: &[(u32, u32, u32)]) -> Mapping {
+        Mapping(
+            transformations
+                .iter()
+                .map(|&(dst_start, src_start, len)| Transformation {
+                    src_start,
+                    len,
+                    dst_start,
+                })
+                .collect(),
+        )
+    }
+
+    /// Maps `ranges`, given as pairs of their start and end
+    fn map_ranges(m: &Mapping, ranges: &[(u64, u64)]) -> Vec<(u64, u64)> {
+        let ranges = ranges.iter().map(|&(start, end)| start..end).collect();
+        let mut mapped: Vec<_> = m
+            .map_ranges(ranges)
+            .into_iter()
+            .map(|r| (r.start, r.end))
+            .collect();
+        mapped.sort_unstable();
+        mapped
+    }
+
+    #[test]
+    fn splits_ranges_at_the_transformations() {
+        let m = mapping(&[(100, 10, 5), (200, 20, 5)]);
+        assert_eq!(
+            map_ranges(&m, &[(0, 30)]),
+            [(0, 10), (15, 20), (25, 30), (100, 105), (200, 205)]
+        );
+        assert_eq!(
+            map_ranges(&m, &[(12, 22)]),
+            [(15, 20), (102, 105), (200, 202)]
+        );
+        assert_eq!(
+            map_ranges(&m, &[(5, 8), (24, 26)]),
+            [(5, 8), (25, 26), (204, 205)]
+        );
     }
 }
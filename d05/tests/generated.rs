@@ -0,0 +1 @@
+common::generated_input_tests!(d05::gen::input, d05::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,27 @@
+//! Sheets of `size` races, at most 4 so that the kerned numbers of part 2 stay manageable
+//!
+//! Every race can be won
+
+use core::fmt::Write;
+
+use common::gen::{Rng, StdRng};
+
+const MAX_RACES: usize = 4;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let races: Vec<(u64, u64)> = (0..size.clamp(1, MAX_RACES))
+        .map(|_| {
+            let time = rng.gen_range(2..100);
+            // the best you can do is to hold the button for half of the race
+            let record = rng.gen_range(0..(time / 2) * (time - time / 2));
+            (time, record)
+        })
+        .collect();
+
+    let (mut times, mut records) = (String::new(), String::new());
+    for (time, record) in races {
+        write!(times, "{time:>5}").unwrap();
+        write!(records, "{record:>5}").unwrap();
+    }
+    format!("Time:    {times}\nDistance:{records}\n")
+}
@@ -1,3 +1,10 @@
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 pub(crate) mod sheet;
+
+const DAY: u8 = 6;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,3 +1,5 @@
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Race {
     pub(crate) time: u64,
     pub(crate) distance: u64,
@@ -20,3 +22,27 @@ impl Race {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A race, with a record anywhere between 0 and the best distance possible
+    fn race() -> impl Strategy<Value = Race> {
+        (0..1000u64)
+            .prop_flat_map(|time| (Just(time), 0..=time * time / 4))
+            .prop_map(|(time, distance)| Race { time, distance })
+    }
+
+    proptest! {
+        #[test]
+        fn ways_to_win_matches_trying_every_hold_time(race in race()) {
+            let Race { time, distance } = race;
+            let ways = (0..=time)
+                .filter(|hold| hold * (time - hold) > distance)
+                .count();
+            prop_assert_eq!(race.ways_to_win(), ways);
+        }
+    }
+}
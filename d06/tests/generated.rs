@@ -0,0 +1 @@
+common::generated_input_tests!(d06::gen::input, d06::SOLUTIONS, [1, 5, 20]);
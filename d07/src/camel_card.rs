@@ -1,12 +1,12 @@
 use core::{cmp::Eq, fmt::Debug, hash::Hash, iter::zip};
-use std::collections::HashMap;
+use std::{collections::HashMap, io::BufRead};
 
+use common::parse::{parse_all, rule, separated_list0, IResult, ParseError};
 use nom::{
     character::complete::{anychar, char, newline, u32},
     combinator::{map, map_res},
-    multi::{many_m_n, separated_list0},
+    multi::many_m_n,
     sequence::separated_pair,
-    IResult,
 };
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -21,6 +21,7 @@ pub(crate) enum HandType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Hand<C>([C; 5]);
 
 impl<C> Hand<C>
@@ -56,6 +57,7 @@ where
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Input<C>(pub(crate) Vec<(Hand<C>, u32)>);
 
 impl<C> Input<C>
@@ -74,41 +76,53 @@ where
 
 fn card<C>(i: &str) -> IResult<&str, C>
 where
-    C: TryFrom<char>,
+    C: TryFrom<char, Error = anyhow::Error>,
 {
-    map_res(anychar, C::try_from)(i)
+    rule("card", map_res(anychar, C::try_from))(i)
 }
 
 fn hand<C>(i: &str) -> IResult<&str, Hand<C>>
 where
-    C: Debug + TryFrom<char>,
+    C: Debug + TryFrom<char, Error = anyhow::Error>,
 {
-    map(many_m_n(5, 5, card), |cs| Hand(cs.try_into().unwrap()))(i)
+    rule(
+        "hand",
+        map(many_m_n(5, 5, card), |cs| Hand(cs.try_into().unwrap())),
+    )(i)
+}
+
+fn hand_with_bid<C>(i: &str) -> IResult<&str, (Hand<C>, u32)>
+where
+    C: Debug + TryFrom<char, Error = anyhow::Error>,
+{
+    rule("hand_with_bid", separated_pair(hand, char(' '), u32))(i)
 }
 
 fn input<C>(i: &str) -> IResult<&str, Input<C>>
 where
-    C: Debug + TryFrom<char>,
+    C: Debug + TryFrom<char, Error = anyhow::Error>,
 {
-    map(
-        separated_list0(newline, separated_pair(hand, char(' '), u32)),
-        Input,
-    )(i)
+    rule("input", map(separated_list0(newline, hand_with_bid), Input))(i)
 }
 
 impl<C> std::str::FromStr for Input<C>
 where
-    C: Debug + TryFrom<char>,
+    C: Debug + TryFrom<char, Error = anyhow::Error>,
 {
-    type Err = nom::error::Error<String>;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use nom::Finish;
-        match input(s).finish() {
-            Ok((_remaining, object)) => Ok(object),
-            Err(nom::error::Error { input, code }) => Err(Self::Err {
-                input: input.to_string(),
-                code,
-            }),
-        }
+        parse_all(input, s)
+    }
+}
+
+impl<C> Input<C>
+where
+    C: Debug + TryFrom<char, Error = anyhow::Error>,
+{
+    /// Reads the hands one line at a time
+    pub(crate) fn from_reader(reader: impl BufRead) -> anyhow::Result<Self> {
+        common::read::lines(reader, |line| parse_all(hand_with_bid, line))
+            .collect::<anyhow::Result<_>>()
+            .map(Self)
     }
 }
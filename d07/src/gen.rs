@@ -0,0 +1,38 @@
+//! Lists of `size` hands with their bids
+//!
+//! The hands are built to cover all the hand types, with and without jokers
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+fn hand(rng: &mut StdRng) -> String {
+    // first pick the pattern of repeated cards (e.g. `[3, 2]` for a full house),
+    // then fill it with distinct card labels
+    const PATTERNS: [&[usize]; 7] = [
+        &[5],
+        &[4, 1],
+        &[3, 2],
+        &[3, 1, 1],
+        &[2, 2, 1],
+        &[2, 1, 1, 1],
+        &[1, 1, 1, 1, 1],
+    ];
+    let pattern = PATTERNS.choose(rng).unwrap();
+    let labels: Vec<_> = CARDS.choose_multiple(rng, pattern.len()).collect();
+
+    let mut hand: Vec<u8> = pattern
+        .iter()
+        .zip(labels)
+        .flat_map(|(&n, &label)| vec![label; n])
+        .collect();
+    hand.shuffle(rng);
+    String::from_utf8(hand).unwrap()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| format!("{} {}\n", hand(rng), rng.gen_range(1..1000)))
+        .collect::<Vec<_>>()
+        .concat()
+}
@@ -1,10 +1,13 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
 use anyhow::bail;
+use common::Part;
 
 use crate::camel_card::{Hand, HandType, Input};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Card {
     Two,
     Three,
@@ -65,20 +68,17 @@ impl From<&Hand<Card>> for HandType {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let i = Input::<Card>::from_str(file)?;
-    Ok(i.total_winnings())
+    p1_from_reader(file.as_bytes())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    Ok(solve(Input::<Card>::from_reader(reader)?))
+}
 
-    #[test_case(EXAMPLE => 6440)]
-    #[test_case(REAL => 253_313_241)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
-    }
+fn solve(i: Input<Card>) -> u32 {
+    i.total_winnings()
 }
+
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| Input::<Card>::from_str(file)?, solve);
@@ -1,9 +1,12 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
 use crate::camel_card::{Hand, HandType, Input};
 use anyhow::bail;
+use common::Part;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Card {
     Joker,
     Two,
@@ -93,20 +96,17 @@ impl From<&Hand<Card>> for HandType {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<u32> {
-    let i = Input::<Card>::from_str(file)?;
-    Ok(i.total_winnings())
+    p2_from_reader(file.as_bytes())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    Ok(solve(Input::<Card>::from_reader(reader)?))
+}
 
-    #[test_case(EXAMPLE => 5905)]
-    #[test_case[REAL => 253_362_743]]
-    fn test_p2(inp: &str) -> u32 {
-        p2(inp).unwrap()
-    }
+fn solve(i: Input<Card>) -> u32 {
+    i.total_winnings()
 }
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Input::<Card>::from_str(file)?, solve);
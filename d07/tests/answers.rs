@@ -0,0 +1,5 @@
+common::answer_tests!(d07::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d07::p1::p1_from_reader,
+    Two => d07::p2::p2_from_reader,
+);
@@ -0,0 +1 @@
+common::generated_input_tests!(d07::gen::input, d07::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,76 @@
+//! Maps with up to 6 ghosts, each at most `size` steps away from its end node
+//!
+//! The ghost starting at `AAA` is exactly `size` steps away from `ZZZ`
+//!
+//! After reaching its end, each ghost goes around in a cycle of the same length,
+//! so there's always a step at which all of them are at their ends at once
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const MAX_GHOSTS: usize = 6;
+
+struct Names(usize);
+
+impl Names {
+    /// A name that's neither a start nor an end
+    fn next(&mut self) -> String {
+        let n = self.0;
+        self.0 += 1;
+        let letter = |i: usize| char::from(b'A' + i as u8);
+        // the last letter is never `A` or `Z`
+        format!(
+            "{}{}{}",
+            letter(n / 24 / 26 % 26),
+            letter(n / 24 % 26),
+            char::from(b'B' + (n % 24) as u8)
+        )
+    }
+}
+
+fn ghost(
+    rng: &mut StdRng,
+    names: &mut Names,
+    [start, end]: [&str; 2],
+    steps: usize,
+) -> Vec<String> {
+    // there are 2 nodes at each step, and both of them lead to both of the next ones,
+    // so it doesn't matter which way the ghost goes
+    let levels: Vec<[String; 2]> = (1..steps).map(|_| [names.next(), names.next()]).collect();
+
+    let mut nodes = vec![];
+    let mut node = |name: &str, [l, r]: &[String; 2]| {
+        let (l, r) = if rng.gen_bool(0.5) { (l, r) } else { (r, l) };
+        nodes.push(format!("{name} = ({l}, {r})"));
+    };
+    let ends = [end.to_string(), end.to_string()];
+    let first = levels.first().unwrap_or(&ends);
+
+    node(start, first);
+    for (level, next) in levels.iter().zip(levels.iter().skip(1).chain([&ends])) {
+        for name in level {
+            node(name, next);
+        }
+    }
+    node(end, first);
+    nodes
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let steps = size.max(1);
+    let moves: String = (0..rng.gen_range(1..=steps))
+        .map(|_| if rng.gen_bool(0.5) { 'L' } else { 'R' })
+        .collect();
+
+    let mut names = Names(0);
+    // the ghost of part 1
+    let mut nodes = ghost(rng, &mut names, ["AAA", "ZZZ"], steps);
+
+    for g in 1..rng.gen_range(1..=MAX_GHOSTS) {
+        let [start, end] = ["A", "Z"].map(|suffix| format!("G{g}{suffix}"));
+        let steps = rng.gen_range(1..=steps);
+        nodes.extend(ghost(rng, &mut names, [&start, &end], steps));
+    }
+    nodes.shuffle(rng);
+
+    format!("{moves}\n\n{}\n", nodes.join("\n"))
+}
@@ -1,17 +1,20 @@
 use std::collections::HashMap;
 
-use anyhow::bail;
-use libaoc::impl_from_str_for_obj_with_lifetimes_from_nom_parser;
+use anyhow::{bail, ensure};
+use common::{
+    impl_from_str_for_obj_with_lifetimes_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
 use nom::{
     bytes::complete::{tag, take},
     character::complete::{anychar, char, newline},
     combinator::{map, map_res},
-    multi::{many1, separated_list1},
+    multi::many1,
     sequence::{delimited, separated_pair},
-    IResult,
 };
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum MoveDirection {
     Left,
     Right,
@@ -31,6 +34,7 @@ impl TryFrom<char> for MoveDirection {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Moves(pub(crate) Vec<MoveDirection>);
 
 type NodeName<'a> = &'a str;
@@ -42,64 +46,94 @@ struct Node<'a> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct NodeNext<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub(crate) left: NodeName<'a>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub(crate) right: NodeName<'a>,
 }
 
 #[derive(Debug)]
-pub(crate) struct Nodes<'a>(pub(crate) HashMap<NodeName<'a>, NodeNext<'a>>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Nodes<'a>(
+    #[cfg_attr(feature = "serde", serde(borrow))] pub(crate) HashMap<NodeName<'a>, NodeNext<'a>>,
+);
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Map<'a> {
     pub(crate) moves: Moves,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     pub(crate) nodes: Nodes<'a>,
 }
 
 fn moove(i: &str) -> IResult<&str, MoveDirection> {
-    map_res(anychar, MoveDirection::try_from)(i)
+    rule("moove", map_res(anychar, MoveDirection::try_from))(i)
 }
 
 fn moves(i: &str) -> IResult<&str, Moves> {
-    map(many1(moove), Moves)(i)
+    rule("moves", map(many1(moove), Moves))(i)
 }
 
 fn node_name(i: &str) -> IResult<&str, NodeName> {
-    take(3u8)(i)
+    rule("node_name", take(3u8))(i)
 }
 
 fn node_next(i: &str) -> IResult<&str, NodeNext> {
-    map(
-        delimited(
-            char('('),
-            separated_pair(node_name, tag(", "), node_name),
-            char(')'),
+    rule(
+        "node_next",
+        map(
+            delimited(
+                char('('),
+                separated_pair(node_name, tag(", "), node_name),
+                char(')'),
+            ),
+            |(left, right)| NodeNext { left, right },
         ),
-        |(left, right)| NodeNext { left, right },
     )(i)
 }
 
 fn node(i: &str) -> IResult<&str, Node> {
-    map(
-        separated_pair(node_name, tag(" = "), node_next),
-        |(name, next)| Node { name, next },
+    rule(
+        "node",
+        map(
+            separated_pair(node_name, tag(" = "), node_next),
+            |(name, next)| Node { name, next },
+        ),
     )(i)
 }
 
 fn nodes(i: &str) -> IResult<&str, Nodes> {
-    map(separated_list1(newline, node), |nodes| {
-        let res = nodes
-            .into_iter()
-            .map(|Node { name, next }| (name, next))
-            .collect();
-        Nodes(res)
-    })(i)
+    rule(
+        "nodes",
+        map_res(separated_list1(newline, node), |nodes| {
+            let res: HashMap<_, _> = nodes
+                .into_iter()
+                .map(|Node { name, next }| (name, next))
+                .collect();
+
+            for (name, NodeNext { left, right }) in &res {
+                for next in [left, right] {
+                    ensure!(
+                        res.contains_key(next),
+                        "node `{name}` leads to undefined node `{next}`"
+                    );
+                }
+            }
+
+            Ok(Nodes(res))
+        }),
+    )(i)
 }
 
 fn parse_map(i: &str) -> IResult<&str, Map> {
-    map(
-        separated_pair(moves, tag("\n\n"), nodes),
-        |(moves, nodes)| Map { moves, nodes },
+    rule(
+        "parse_map",
+        map(
+            separated_pair(moves, tag("\n\n"), nodes),
+            |(moves, nodes)| Map { moves, nodes },
+        ),
     )(i)
 }
 
@@ -1,10 +1,20 @@
+use anyhow::ensure;
+use common::Part;
+
 use crate::map::{Map, MoveDirection};
 
 const START: &str = "AAA";
 const DESTINATION: &str = "ZZZ";
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
-    let Map { moves, nodes } = Map::try_from(file)?;
+    solve(Map::try_from(file)?)
+}
+
+fn solve(Map { moves, nodes }: Map) -> anyhow::Result<u32> {
+    ensure!(nodes.0.contains_key(START), "there is no `{START}` node");
+
+    // after this many moves, we must be going in circles
+    let max_moves = nodes.0.len() * moves.0.len();
 
     let mut node = START;
     let mut nmoves = 0;
@@ -12,6 +22,10 @@ pub fn p1(file: &str) -> anyhow::Result<u32> {
         if node == DESTINATION {
             break;
         }
+        ensure!(
+            (nmoves as usize) < max_moves,
+            "`{DESTINATION}` can't be reached from `{START}`"
+        );
         let nexts = &nodes.0[node];
         node = match moove {
             MoveDirection::Left => nexts.left,
@@ -22,18 +36,20 @@ pub fn p1(file: &str) -> anyhow::Result<u32> {
     Ok(nmoves)
 }
 
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| Map::try_from(file)?, solve);
+
 #[cfg(test)]
 mod test {
     use super::*;
     use test_case::test_case;
-    const EXAMPLE1: &str = include_str!("../inputs/example1.txt");
-    const EXAMPLE2: &str = include_str!("../inputs/example2.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE1 => 2)]
-    #[test_case(EXAMPLE2 => 6)]
-    #[test_case(REAL => 11309)]
-    fn test_p1(inp: &str) -> u32 {
-        p1(inp).unwrap()
+
+    #[test_case("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)" => "there is no `AAA` node"; "no start")]
+    #[test_case("LR\n\nAAA = (BBB, AAA)\nBBB = (AAA, BBB)\nZZZ = (ZZZ, ZZZ)" => "`ZZZ` can't be reached from `AAA`"; "end unreachable")]
+    #[test_case("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)" => "line 3, column 1: expected `nodes`: node `AAA` leads to undefined node `BBB`"; "dangling node")]
+    fn invalid(input: &str) -> String {
+        let e = p1(input).unwrap_err().to_string();
+        e.lines().next().unwrap().to_string()
     }
 }
@@ -1,47 +1,188 @@
-use crate::map::{Map, MoveDirection};
+use std::collections::HashSet;
+
+use anyhow::{bail, ensure, Context};
+use common::{
+    cycle::{self, chinese_remainder, Cycle},
+    Part,
+};
 use num::Integer;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-pub fn p2(file: &str) -> anyhow::Result<u64> {
-    let is_start = |name: &&str| name.ends_with('A');
-    let is_end = |name: &str| name.ends_with('Z');
+use crate::map::{Map, MoveDirection, Nodes};
 
-    let Map { moves, nodes } = Map::try_from(file)?;
+/// How many systems of congruences to keep track of at most, as each ghost multiplies them
+/// by the number of steps in its cycle at which it's at an end
+const MAX_CONGRUENCES: usize = 1 << 16;
 
-    let curr_nodes: Vec<_> = nodes.0.keys().copied().filter(is_start).collect();
+/// Where a ghost is: at which node, and which of the moves it's about to make
+type State<'a> = (&'a str, usize);
 
-    let times_to_reach_end = curr_nodes.into_iter().map(|mut node| {
-        let mut nmoves = 0;
-        for moove in moves.0.iter().cycle() {
-            if is_end(node) {
-                break;
-            }
+fn is_end(node: &str) -> bool {
+    node.ends_with('Z')
+}
 
+/// The steps after which a ghost is at a node ending with `Z`
+struct Ghost {
+    cycle: Cycle,
+    /// All such steps up to the end of the first round of the cycle,
+    /// after which they repeat every `cycle.period` steps
+    ends: HashSet<usize>,
+}
+
+impl Ghost {
+    fn new<'a>(start: &'a str, moves: &[MoveDirection], nodes: &Nodes<'a>) -> Self {
+        let step = |&(node, i): &State<'a>| {
             let nexts = &nodes.0[node];
-            node = match moove {
+            let node = match moves[i] {
                 MoveDirection::Left => nexts.left,
                 MoveDirection::Right => nexts.right,
             };
+            (node, (i + 1) % moves.len())
+        };
 
-            nmoves += 1;
+        let cycle = cycle::find_by_key((start, 0), step, |&state| state);
+        let mut state = (start, 0);
+        let mut ends = HashSet::new();
+        for t in 0..cycle.start + cycle.period {
+            if is_end(state.0) {
+                ends.insert(t);
+            }
+            state = step(&state);
         }
-        nmoves
-    });
+        Self { cycle, ends }
+    }
+
+    fn is_at_end(&self, t: usize) -> bool {
+        self.ends.contains(&self.cycle.reduce(t))
+    }
+
+    /// The remainders modulo `cycle.period` of the steps after `cycle.start`
+    /// at which the ghost is at an end
+    fn end_remainders(&self) -> impl Iterator<Item = usize> + '_ {
+        self.ends
+            .iter()
+            .filter(|&&t| t >= self.cycle.start)
+            .map(|t| t % self.cycle.period)
+    }
+}
+
+pub fn p2(file: &str) -> anyhow::Result<u64> {
+    solve(Map::try_from(file)?)
+}
 
-    let res = times_to_reach_end.reduce(|acc, n| acc.lcm(&n)).unwrap();
+fn solve(Map { moves, nodes }: Map) -> anyhow::Result<u64> {
+    let starts: Vec<_> = nodes
+        .0
+        .keys()
+        .copied()
+        .filter(|n| n.ends_with('A'))
+        .collect();
+    if starts.is_empty() {
+        bail!("there are no nodes ending with `A` to start from");
+    }
+
+    #[cfg(feature = "parallel")]
+    let starts = starts.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let starts = starts.into_iter();
+    let ghosts: Vec<_> = starts
+        .map(|start| {
+            let ghost = Ghost::new(start, &moves.0, &nodes);
+            if ghost.ends.is_empty() {
+                bail!("no node ending with `Z` can be reached from `{start}`");
+            }
+            Ok(ghost)
+        })
+        .collect();
+    // the first start's error, however the ghosts were walked
+    let ghosts = ghosts.into_iter().collect::<anyhow::Result<Vec<_>>>()?;
+
+    // before all the ghosts are going in circles, just check each step
+    let all_cycling = ghosts.iter().map(|g| g.cycle.start).max().unwrap_or(0);
+    if let Some(t) = (0..all_cycling).find(|&t| ghosts.iter().all(|g| g.is_at_end(t))) {
+        return Ok(t as u64);
+    }
+
+    // after that, the steps at which all of them are at an end are the solutions
+    // of one of the systems of congruences made of one end of each ghost
+    let congruences = ghosts.iter().try_fold(vec![(0, 1)], |congruences, g| {
+        let period = g.cycle.period as i128;
+        let mut congruences: Vec<_> = congruences
+            .iter()
+            .flat_map(|&c| g.end_remainders().map(move |r| (c, (r as i128, period))))
+            .filter_map(|(c, c2)| chinese_remainder(c, c2))
+            .collect();
+        // different ends can make for the same steps
+        congruences.sort_unstable();
+        congruences.dedup();
+        ensure!(
+            congruences.len() <= MAX_CONGRUENCES,
+            "the ghosts are at nodes ending with `Z` in too many ways to keep track of"
+        );
+        // keep clear of overflowing when combining the next one
+        ensure!(
+            congruences.iter().all(|&(_, m)| m <= i128::from(u64::MAX)),
+            "way too many steps to count"
+        );
+        Ok(congruences)
+    })?;
 
-    Ok(res)
+    let all_cycling = all_cycling as i128;
+    let t = congruences
+        .into_iter()
+        .map(|(r, m)| r + Integer::div_ceil(&(all_cycling - r).max(0), &m) * m)
+        .min()
+        .context("the ghosts are never at nodes ending with `Z` all at the same time")?;
+    Ok(u64::try_from(t)?)
 }
 
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Map::try_from(file)?, solve);
+
 #[cfg(test)]
 mod test {
     use super::*;
     use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example3.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
 
-    #[test_case(EXAMPLE => 6)]
-    #[test_case(REAL => 13_740_108_158_591)]
-    fn test_p2(inp: &str) -> u64 {
-        p2(inp).unwrap()
+    #[test_case("L\n\nBBB = (BBB, BBB)" => "there are no nodes ending with `A` to start from"; "no start")]
+    #[test_case("LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22A, 22A)\nXXX = (XXX, XXX)" => "no node ending with `Z` can be reached from `22A`"; "end unreachable")]
+    #[test_case("L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (11B, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n22Z = (22Z, XXX)\nXXX = (XXX, XXX)" => "the ghosts are never at nodes ending with `Z` all at the same time"; "ends only in the prefix")]
+    fn invalid(input: &str) -> String {
+        p2(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn too_many_steps() {
+        use core::fmt::Write;
+
+        // ghosts going round cycles of the first 16 primes, whose product is more than `u64::MAX`
+        let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+        let mut input = String::from("L\n");
+        for (g, p) in ('a'..).zip(primes) {
+            // `A`, then round `01`, .., `Z` over and over again
+            let name = |i: usize| match i {
+                0 => format!("{g}0A"),
+                i if i == p => format!("{g}0Z"),
+                i => format!("{g}{i:02}"),
+            };
+            for i in 0..=p {
+                let next = name(if i == p { 1 } else { i + 1 });
+                write!(input, "\n{} = ({next}, {next})", name(i)).unwrap();
+            }
+        }
+        assert_eq!(
+            p2(&input).unwrap_err().to_string(),
+            "way too many steps to count"
+        );
+    }
+
+    // the first ghost reaches `11Z` every 2 steps, but the second one only gets into
+    // its cycle of 3 steps after reaching `22Z` for the first time
+    #[test_case("L\n\n11A = (11B, XXX)\n11B = (11Z, XXX)\n11Z = (11B, XXX)\n22A = (22Z, XXX)\n22Z = (22B, XXX)\n22B = (22C, XXX)\n22C = (22Z, XXX)\nXXX = (XXX, XXX)" => 4; "cycles with prefixes")]
+    #[test_case("LLR\n\n11A = (11Z, XXX)\n11Z = (22Z, 11Z)\n22A = (22Z, XXX)\n22Z = (11Z, 22Z)\nXXX = (XXX, XXX)" => 1; "both end after one step")]
+    fn cycles(input: &str) -> u64 {
+        p2(input).unwrap()
     }
 }
@@ -0,0 +1 @@
+common::generated_input_tests!(d08::gen::input, d08::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,36 @@
+//! OASIS reports of `size` value histories
+//!
+//! Each history is a polynomial sequence, so extrapolating it always works out
+
+use common::gen::{Rng, StdRng};
+
+const HISTORY_LEN: usize = 21;
+const MAX_DEGREE: usize = 5;
+
+/// A history whose `degree`th differences are all the same
+pub fn history(rng: &mut StdRng, degree: usize) -> Vec<i32> {
+    let mut values = vec![rng.gen_range(-5..=5); HISTORY_LEN];
+    // undo the differences one by one, starting from a random value each time
+    for _ in 0..degree {
+        let mut curr = rng.gen_range(-20..=20);
+        values = values
+            .into_iter()
+            .map(|diff| {
+                let value = curr;
+                curr += diff;
+                value
+            })
+            .collect();
+    }
+    values
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let degree = rng.gen_range(0..=MAX_DEGREE);
+            let history: Vec<_> = history(rng, degree).iter().map(i32::to_string).collect();
+            history.join(" ") + "\n"
+        })
+        .collect()
+}
@@ -1,51 +1,86 @@
-use libaoc::impl_from_str_from_nom_parser;
+use common::{
+    differences, impl_from_str_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
 use nom::{
     character::complete::{char, i32, newline},
     combinator::map,
-    multi::separated_list1,
-    IResult,
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct ValueHistory(Vec<i32>);
 
 impl ValueHistory {
-    fn derivatives(self) -> Vec<Vec<i32>> {
-        let mut curr_derivative = self.0;
-        let mut derivatives = vec![];
-        while !curr_derivative.iter().all(|n| n == &0) {
-            // TODO: use `array_windows`
-            let next_derivative = curr_derivative
-                .windows(2)
-                .map(|pair| pair[1] - pair[0])
-                .collect();
-            derivatives.push(std::mem::replace(&mut curr_derivative, next_derivative));
-        }
-        derivatives
-    }
-
     pub(crate) fn extrapolate(self) -> i32 {
-        self.derivatives()
-            .into_iter()
-            .map(|v| *v.last().expect("enough data points to extrapolate"))
-            .sum()
+        differences::extrapolate(self.0)
     }
 
     pub(crate) fn extrapolate_back(self) -> i32 {
-        self.derivatives()
-            .into_iter()
-            .map(|v| *v.first().expect("enough data points to extrapolate"))
-            .rfold(0, |acc, n| n - acc)
+        differences::extrapolate_back(self.0)
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct OasisReport(pub(crate) Vec<ValueHistory>);
 
 fn value_history(i: &str) -> IResult<&str, ValueHistory> {
-    map(separated_list1(char(' '), i32), ValueHistory)(i)
+    rule(
+        "value_history",
+        map(separated_list1(char(' '), i32), ValueHistory),
+    )(i)
 }
 
 fn oasis_report(i: &str) -> IResult<&str, OasisReport> {
-    map(separated_list1(newline, value_history), OasisReport)(i)
+    rule(
+        "oasis_report",
+        map(separated_list1(newline, value_history), OasisReport),
+    )(i)
 }
 
 impl_from_str_from_nom_parser!(oasis_report, OasisReport);
+impl_from_str_from_nom_parser!(value_history, ValueHistory);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn polynomial(coefficients: &[i32], x: i32) -> i32 {
+        coefficients.iter().rev().fold(0, |acc, c| acc * x + c)
+    }
+
+    /// The coefficients of a polynomial, and how many of its values to take as the history,
+    /// enough to tell the polynomial apart
+    fn sampled_polynomial() -> impl Strategy<Value = (Vec<i32>, i32)> {
+        prop::collection::vec(-5..=5, 1..=4).prop_flat_map(|coefficients| {
+            let min_len = coefficients.len() as i32;
+            (Just(coefficients), min_len..=10)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn extrapolates_polynomials((coefficients, len) in sampled_polynomial()) {
+            let values: Vec<i32> = (0..len).map(|x| polynomial(&coefficients, x)).collect();
+            prop_assert_eq!(
+                ValueHistory(values.clone()).extrapolate(),
+                polynomial(&coefficients, len)
+            );
+            prop_assert_eq!(
+                ValueHistory(values).extrapolate_back(),
+                polynomial(&coefficients, -1)
+            );
+        }
+
+        #[test]
+        fn extrapolate_back_is_extrapolating_the_reversed_history(
+            values in prop::collection::vec(-50..=50, 1..10)
+        ) {
+            let reversed = values.iter().rev().copied().collect();
+            prop_assert_eq!(
+                ValueHistory(values).extrapolate_back(),
+                ValueHistory(reversed).extrapolate()
+            );
+        }
+    }
+}
@@ -1,24 +1,24 @@
 use core::str::FromStr;
+use std::io::BufRead;
+
+use common::Part;
 
 use crate::oasis::{OasisReport, ValueHistory};
 
 pub fn p1(file: &str) -> anyhow::Result<i32> {
-    let report = OasisReport::from_str(file)?;
-
-    let res = report.0.into_iter().map(ValueHistory::extrapolate).sum();
-    Ok(res)
+    p1_from_reader(file.as_bytes())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<i32> {
+    common::read::lines(reader, ValueHistory::from_str)
+        .map(|history| Ok(history?.extrapolate()))
+        .sum()
+}
 
-    #[test_case(EXAMPLE => 114)]
-    #[test_case(REAL => 1_901_217_887)]
-    fn test_p1(inp: &str) -> i32 {
-        p1(inp).unwrap()
-    }
+fn solve(report: OasisReport) -> i32 {
+    report.0.into_iter().map(ValueHistory::extrapolate).sum()
 }
+
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| OasisReport::from_str(file)?, solve);
@@ -1,28 +1,28 @@
 use core::str::FromStr;
+use std::io::BufRead;
+
+use common::Part;
 
 use crate::oasis::{OasisReport, ValueHistory};
 
 pub fn p2(file: &str) -> anyhow::Result<i32> {
-    let report = OasisReport::from_str(file)?;
+    p2_from_reader(file.as_bytes())
+}
 
-    let res = report
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<i32> {
+    common::read::lines(reader, ValueHistory::from_str)
+        .map(|history| Ok(history?.extrapolate_back()))
+        .sum()
+}
+
+fn solve(report: OasisReport) -> i32 {
+    report
         .0
         .into_iter()
         .map(ValueHistory::extrapolate_back)
-        .sum();
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P2;
 
-    #[test_case(EXAMPLE => 2)]
-    #[test_case(REAL => 905)]
-    fn test_p2(inp: &str) -> i32 {
-        p2(inp).unwrap()
-    }
-}
+common::solution!(P2, Part::Two, |file| OasisReport::from_str(file)?, solve);
@@ -0,0 +1,5 @@
+common::answer_tests!(d09::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d09::p1::p1_from_reader,
+    Two => d09::p2::p2_from_reader,
+);
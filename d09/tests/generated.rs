@@ -0,0 +1 @@
+common::generated_input_tests!(d09::gen::input, d09::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,211 @@
+//! Pipe mazes of `(2 * size + 1)`×`(2 * size + 1)` tiles, with exactly one loop, which goes through `S`
+//!
+//! The loop is the outline of a random blob of cells on a `size`×`size` grid.
+//! Each cell corner and each cell side becomes a tile, so that the outline can be drawn with pipes.
+//! The blob has no holes and no cells touching only diagonally, so its outline is a single loop
+//! that doesn't touch itself. The tiles off the loop are random junk
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+/// A maze, and the answers to both parts for it
+pub struct Maze {
+    pub input: String,
+    pub loop_len: usize,
+    pub enclosed: usize,
+}
+
+struct Blob {
+    size: usize,
+    cells: Vec<Vec<bool>>,
+}
+
+impl Blob {
+    fn contains(&self, x: isize, y: isize) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return false;
+        };
+        x < self.size && y < self.size && self.cells[y][x]
+    }
+
+    /// Whether adding the cell at `(x, y)` keeps the cells outside the blob connected
+    ///
+    /// That's the case if the blob's cells around it form a single unbroken arc, as then
+    /// the outside cells around it form one too, and can reach each other around the new cell
+    fn can_grow_into(&self, x: isize, y: isize) -> bool {
+        const RING: [(isize, isize); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        let inside = RING.map(|(dx, dy)| self.contains(x + dx, y + dy));
+        let n_arcs = (0..RING.len())
+            .filter(|&i| !inside[i] && inside[(i + 1) % RING.len()])
+            .count();
+        n_arcs == 1
+    }
+
+    /// Whether any 2×2 square around cell `(x, y)` has only diagonally touching cells of the blob
+    fn touches_diagonally(&self, x: isize, y: isize) -> bool {
+        [(-1, -1), (-1, 0), (0, -1), (0, 0)].iter().any(|(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            let [tl, tr, bl, br] =
+                [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)].map(|(x, y)| self.contains(x, y));
+            tl == br && tr == bl && tl != tr
+        })
+    }
+
+    fn random(rng: &mut StdRng, size: usize) -> Self {
+        let mut blob = Self {
+            size,
+            cells: vec![vec![false; size]; size],
+        };
+        let mut cells = vec![(rng.gen_range(0..size), rng.gen_range(0..size))];
+        blob.cells[cells[0].1][cells[0].0] = true;
+
+        let target = rng.gen_range(1..=size * size / 2 + 1);
+        for _ in 0..20 * target {
+            if cells.len() == target {
+                break;
+            }
+            let &(x, y) = cells.choose(rng).unwrap();
+            let (dx, dy) = *[(0, -1), (0, 1), (-1, 0), (1, 0)].choose(rng).unwrap();
+            let (x, y) = (x as isize + dx, y as isize + dy);
+            if !(0..size as isize).contains(&x) || !(0..size as isize).contains(&y) {
+                continue;
+            }
+            if blob.contains(x, y) {
+                continue;
+            }
+
+            if !blob.can_grow_into(x, y) {
+                continue;
+            }
+            blob.cells[y as usize][x as usize] = true;
+            if blob.touches_diagonally(x, y) {
+                blob.cells[y as usize][x as usize] = false;
+            } else {
+                cells.push((x as usize, y as usize));
+            }
+        }
+        blob
+    }
+}
+
+pub fn maze(rng: &mut StdRng, size: usize) -> Maze {
+    let size = size.max(1);
+    let blob = Blob::random(rng, size);
+    let width = 2 * size + 1;
+
+    // whether the outline goes from the corner at `(x, y)` to the one right of/below it
+    let right = |x: isize, y: isize| blob.contains(x, y - 1) != blob.contains(x, y);
+    let down = |x: isize, y: isize| blob.contains(x - 1, y) != blob.contains(x, y);
+
+    let mut tiles = vec![vec![b'.'; width]; width];
+    let mut on_loop = vec![vec![false; width]; width];
+    let mut enclosed = 0;
+    for (ty, row) in tiles.iter_mut().enumerate() {
+        for (tx, tile) in row.iter_mut().enumerate() {
+            let (x, y) = ((tx / 2) as isize, (ty / 2) as isize);
+            let (pipe, inside) = match (tx % 2, ty % 2) {
+                // a corner
+                (0, 0) => {
+                    let sides = [down(x, y - 1), down(x, y), right(x - 1, y), right(x, y)];
+                    let pipe = match sides {
+                        [true, true, false, false] => Some(b'|'),
+                        [false, false, true, true] => Some(b'-'),
+                        [true, false, false, true] => Some(b'L'),
+                        [true, false, true, false] => Some(b'J'),
+                        [false, true, true, false] => Some(b'7'),
+                        [false, true, false, true] => Some(b'F'),
+                        _ => None,
+                    };
+                    let inside = [(x - 1, y - 1), (x, y - 1), (x - 1, y), (x, y)]
+                        .iter()
+                        .all(|&(x, y)| blob.contains(x, y));
+                    (pipe, inside)
+                }
+                // the top width of a cell
+                (1, 0) => (
+                    right(x, y).then_some(b'-'),
+                    blob.contains(x, y - 1) && blob.contains(x, y),
+                ),
+                // the left width of a cell
+                (0, 1) => (
+                    down(x, y).then_some(b'|'),
+                    blob.contains(x - 1, y) && blob.contains(x, y),
+                ),
+                // the middle of a cell
+                _ => (None, blob.contains(x, y)),
+            };
+
+            if let Some(pipe) = pipe {
+                *tile = pipe;
+                on_loop[ty][tx] = true;
+            } else {
+                *tile = *b"|-LJ7F..".choose(rng).unwrap();
+                enclosed += usize::from(inside);
+            }
+        }
+    }
+
+    let loop_tiles: Vec<(usize, usize)> = (0..width)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| on_loop[y][x])
+        .collect();
+    let &(sx, sy) = loop_tiles.choose(rng).unwrap();
+    tiles[sy][sx] = b'S';
+    // junk pipes next to the start mustn't look like they're connected to it
+    for (x, y) in [
+        (sx.wrapping_sub(1), sy),
+        (sx + 1, sy),
+        (sx, sy.wrapping_sub(1)),
+        (sx, sy + 1),
+    ] {
+        if x < width && y < width && !on_loop[y][x] {
+            tiles[y][x] = b'.';
+        }
+    }
+
+    let input = tiles
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect();
+    Maze {
+        input,
+        loop_len: loop_tiles.len(),
+        enclosed,
+    }
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    maze(rng, size).input
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_answers() {
+        for seed in 0..20 {
+            let maze = maze(&mut common::gen::rng(seed), 8);
+            assert_eq!(
+                crate::p1::p1(&maze.input).unwrap(),
+                maze.loop_len / 2,
+                "{}",
+                maze.input
+            );
+            assert_eq!(
+                crate::p2::p2(&maze.input).unwrap(),
+                maze.enclosed,
+                "{}",
+                maze.input
+            );
+        }
+    }
+}
@@ -1,3 +1,11 @@
+use common::Solution;
+
+pub mod gen;
 pub(crate) mod map;
 pub mod p1;
 pub mod p2;
+pub mod render;
+
+const DAY: u8 = 10;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
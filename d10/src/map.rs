@@ -1,34 +1,11 @@
 use core::str::FromStr;
 
-use anyhow::bail;
-use itertools::Itertools;
+use anyhow::{bail, Context};
+use grid::{Direction, Grid, Pos};
 use libaoc::points::{two_d::Border2D, Point2D};
 
-type Pos = Point2D<usize>;
-
-trait NewTrait {
-    fn north(self) -> Self;
-    fn south(self) -> Self;
-    fn east(self) -> Self;
-    fn west(self) -> Self;
-}
-
-impl NewTrait for Pos {
-    fn north(self) -> Self {
-        Point2D(self.x(), self.y() - 1)
-    }
-    fn south(self) -> Self {
-        Point2D(self.x(), self.y() + 1)
-    }
-    fn east(self) -> Self {
-        Point2D(self.x() + 1, self.y())
-    }
-    fn west(self) -> Self {
-        Point2D(self.x() - 1, self.y())
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum PipeDirection {
     NorthSouth,
     EastWest,
@@ -38,6 +15,20 @@ pub(crate) enum PipeDirection {
     SouthEast,
 }
 
+impl PipeDirection {
+    /// The directions the two ends of the pipe point in
+    fn ends(self) -> [Direction; 2] {
+        match self {
+            Self::NorthSouth => [Direction::Up, Direction::Down],
+            Self::EastWest => [Direction::Right, Direction::Left],
+            Self::NorthWest => [Direction::Up, Direction::Left],
+            Self::NorthEast => [Direction::Up, Direction::Right],
+            Self::SouthWest => [Direction::Down, Direction::Left],
+            Self::SouthEast => [Direction::Down, Direction::Right],
+        }
+    }
+}
+
 use PipeDirection::EastWest as EW;
 use PipeDirection::NorthEast as NE;
 use PipeDirection::NorthSouth as NS;
@@ -46,7 +37,8 @@ use PipeDirection::SouthEast as SE;
 use PipeDirection::SouthWest as SW;
 
 #[derive(Debug, Clone, Copy)]
-enum Point {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Point {
     Pipe(PipeDirection),
     Ground,
 }
@@ -70,20 +62,12 @@ impl TryFrom<char> for Point {
 }
 
 impl Point {
-    pub fn points_west(self) -> bool {
-        matches!(self, Self::Pipe(NW | SW | EW),)
-    }
-
-    pub fn points_east(self) -> bool {
-        matches!(self, Self::Pipe(NE | EW | SE))
-    }
-
-    pub fn points_south(self) -> bool {
-        matches!(self, Self::Pipe(SE | NS | SW))
-    }
-
-    pub fn points_north(self) -> bool {
-        matches!(self, Self::Pipe(NE | NS | NW))
+    /// Whether this is a pipe with one end pointing in `direction`
+    pub fn points(self, direction: Direction) -> bool {
+        match self {
+            Self::Pipe(pd) => pd.ends().contains(&direction),
+            Self::Ground => false,
+        }
     }
 
     fn turn_clockwise(self) -> Self {
@@ -105,102 +89,90 @@ impl Point {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Map {
-    inner: Vec<Vec<Point>>,
-    start_pos: Pos,
+    pub(crate) inner: Grid<Point>,
+    #[cfg_attr(feature = "serde", serde(with = "grid::serialize::pos"))]
+    pub(crate) start_pos: Pos,
 }
 
 impl FromStr for Map {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let width = s.lines().next().unwrap().len();
-        let height = s.lines().count();
-
-        let start_pos = s
-            .lines()
-            .enumerate()
-            .find_map(|(y, line)| line.chars().position(|p| p == 'S').map(|x| Point2D(x, y)))
-            .unwrap();
+        // the start is parsed as ground, and replaced by the pipe under it
+        // once the pipes around it are known
+        let mut inner = Grid::parse(s, |c| match c {
+            'S' => Ok(Point::Ground),
+            c => Point::try_from(c),
+        })?;
+
+        let mut starts = s.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == 'S')
+                .map(move |(x, _)| Point2D(x, y))
+        });
+        let Some(start_pos) = starts.next() else {
+            bail!("no start position `S` found");
+        };
+        if let Some(other) = starts.next() {
+            bail!("more than one start position `S`: {start_pos:?} and {other:?}");
+        }
 
         let pipe_under_start = {
-            // NOTE: can't use `Map::get` since the instance is not constructed yet
-            let get = |Point2D(x, y)| -> Point {
-                s.lines()
-                    .nth(y)
-                    .unwrap()
-                    .chars()
-                    .nth(x)
-                    .unwrap()
-                    .try_into()
-                    .unwrap()
-            };
-
-            let p @ Point2D(x, y) = start_pos;
-
-            let pipe_to_west = x > 0 && get(p.west()).points_east();
-            let pipe_to_east = x < width - 1 && get(p.east()).points_west();
-            let pipe_to_north = y > 0 && get(p.north()).points_south();
-            let pipe_to_south = y < height - 1 && get(p.south()).points_north();
-
-            let pd = match (pipe_to_west, pipe_to_east, pipe_to_north, pipe_to_south) {
-                (true, true, false, false) => EW,
-                (true, false, true, false) => NW,
-                (true, false, false, true) => SW,
-                (false, true, true, false) => NE,
-                (false, true, false, true) => SE,
-                (false, false, true, true) => NS,
-                _ => unreachable!("there must be pipes to exactly two sides"),
+            // whether the neighbour in `d` is a pipe pointing back at the start
+            let connected = |d: Direction| {
+                inner
+                    .step(start_pos, d)
+                    .is_some_and(|p| inner[p].points(d.reverse()))
             };
-
-            Point::Pipe(pd)
+            let connections: Vec<_> = Direction::ALL
+                .into_iter()
+                .filter(|&d| connected(d))
+                .collect();
+
+            match connections[..] {
+                [Direction::Right, Direction::Left] => EW,
+                [Direction::Up, Direction::Left] => NW,
+                [Direction::Down, Direction::Left] => SW,
+                [Direction::Up, Direction::Right] => NE,
+                [Direction::Right, Direction::Down] => SE,
+                [Direction::Up, Direction::Down] => NS,
+                _ => bail!(
+                    "the start at {start_pos:?} must be connected to pipes on exactly two sides"
+                ),
+            }
         };
 
-        let mut map = Vec::with_capacity(height);
-        for (y, line) in s.lines().enumerate() {
-            let line: Vec<Point> = line
-                .char_indices()
-                .map(|(x, c)| {
-                    if Point2D(x, y) == start_pos {
-                        Ok(pipe_under_start)
-                    } else {
-                        Point::try_from(c)
-                    }
-                })
-                .try_collect()?;
-            map.push(line);
-        }
+        inner[start_pos] = Point::Pipe(pipe_under_start);
 
-        Ok(Self {
-            inner: map,
-            start_pos,
-        })
+        Ok(Self { inner, start_pos })
     }
 }
 
 impl Map {
-    fn get(&self, &Point2D(x, y): &Pos) -> &Point {
-        &self.inner[y][x]
+    fn get(&self, p: &Pos) -> &Point {
+        &self.inner[*p]
     }
 
-    fn adjacent(&self, &p: &Pos) -> Option<[Pos; 2]> {
-        match self.get(&p) {
+    fn try_get(&self, p: &Pos) -> Option<&Point> {
+        self.inner.get(*p)
+    }
+
+    /// The positions the pipe at `p` leads to, or `None` if there's no pipe
+    /// at `p` or it leads off the map
+    fn adjacent(&self, p: &Pos) -> Option<[Pos; 2]> {
+        match self.try_get(p)? {
             Point::Pipe(pd) => {
-                let res = match pd {
-                    NS => [p.north(), p.south()],
-                    EW => [p.east(), p.west()],
-                    NW => [p.north(), p.west()],
-                    NE => [p.north(), p.east()],
-                    SW => [p.south(), p.west()],
-                    SE => [p.south(), p.east()],
-                };
-                Some(res)
+                let [a, b] = pd.ends();
+                Some([self.inner.step(*p, a)?, self.inner.step(*p, b)?])
             }
             Point::Ground => None,
         }
     }
 
-    pub(crate) fn find_loop(&self) -> Vec<Pos> {
+    pub(crate) fn find_loop(&self) -> anyhow::Result<Vec<Pos>> {
         let mut looop = vec![];
 
         let mut curr = self.start_pos;
@@ -208,17 +180,16 @@ impl Map {
         // we will be traversing the loop
         let mut next = self
             .adjacent(&curr)
-            .expect("pipes in the loop only ever lead to other pipes")[0];
+            .with_context(|| format!("the pipe under the start at {curr:?} leads off the map"))?[0];
 
         loop {
-            let curr_and_next_next = self
-                .adjacent(&next)
-                .expect("pipes in the loop only ever lead to other pipes");
-
-            let next_next = if curr_and_next_next[0] == curr {
-                curr_and_next_next[1]
-            } else {
-                curr_and_next_next[0]
+            let Some(curr_and_next_next) = self.adjacent(&next) else {
+                bail!("the loop leads from {curr:?} to {next:?}, which is not a pipe on the map");
+            };
+
+            let next_next = match curr_and_next_next {
+                [c, next_next] | [next_next, c] if c == curr => next_next,
+                _ => bail!("the pipe at {next:?} is not connected back to {curr:?}"),
             };
 
             if next_next == self.start_pos {
@@ -229,7 +200,7 @@ impl Map {
             looop.push(curr);
             (curr, next) = (next, next_next);
         }
-        looop
+        Ok(looop)
     }
 
     /// counts the number of times a horizontal line from `point` crosses the loop
@@ -355,3 +326,23 @@ impl Map {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn find_loop(input: &str) -> anyhow::Result<Vec<Pos>> {
+        Map::from_str(input)?.find_loop()
+    }
+
+    #[test_case(".....\n.F-7.\n.|.|.\n.L-J.\n....." => "no start position `S` found"; "no start")]
+    #[test_case("S-7\n|.|\nL-S" => "more than one start position `S`: Point2D(0, 0) and Point2D(2, 2)"; "two starts")]
+    #[test_case("S-7\n..|\n.-J" => "the start at Point2D(0, 0) must be connected to pipes on exactly two sides"; "start with one pipe")]
+    #[test_case(".....\n-S-7.\n.|.|.\n.L-J." => "the start at Point2D(1, 1) must be connected to pipes on exactly two sides"; "start with three pipes")]
+    #[test_case(".....\n.S-7.\n.|.|.\n.L|J.\n....." => "the pipe at Point2D(2, 3) is not connected back to Point2D(1, 3)"; "broken loop")]
+    #[test_case("S-7\n|.|\nL-.\n" => "the loop leads from Point2D(1, 2) to Point2D(2, 2), which is not a pipe on the map"; "loop into the ground")]
+    fn invalid(input: &str) -> String {
+        find_loop(input).unwrap_err().to_string()
+    }
+}
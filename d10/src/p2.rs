@@ -1,16 +1,19 @@
 use core::str::FromStr;
 
 use crate::map::Map;
+use common::Part;
 use libaoc::points::{
     two_d::{min_enclosing_rectangle, Border2D},
     Point2D,
 };
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let map = Map::from_str(file)?;
-    // eprintln!("{map}");
+    solve(&Map::from_str(file)?)
+}
 
-    let looop = map.find_loop();
+fn solve(map: &Map) -> anyhow::Result<usize> {
+    let looop = map.find_loop()?;
+    tracing::debug!(len = looop.len(), "found the loop");
 
     let border @ Border2D {
         left,
@@ -23,26 +26,11 @@ pub fn p2(file: &str) -> anyhow::Result<usize> {
         .flat_map(|y| (left..=right).map(move |x| Point2D(x, y)))
         .filter(|&p| map.is_inside_loop(&looop, &border, p))
         .count();
-
     Ok(res)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE3: &str = include_str!("../inputs/example3.txt");
-    const EXAMPLE4: &str = include_str!("../inputs/example4.txt");
-    const EXAMPLE5: &str = include_str!("../inputs/example5.txt");
-    const EXAMPLE6: &str = include_str!("../inputs/example6.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P2;
 
-    #[test_case(EXAMPLE3 => 4)]
-    #[test_case(EXAMPLE4 => 4)]
-    #[test_case(EXAMPLE5 => 8)]
-    #[test_case(EXAMPLE6 => 10)]
-    #[test_case(REAL => 449)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
-    }
-}
+common::solution!(P2, Part::Two, |file| Map::from_str(file)?, |map| {
+    solve(&map)
+});
@@ -0,0 +1,40 @@
+//! The loop among all the other pipes, with the tiles inside of it shaded
+
+use core::str::FromStr;
+
+use grid::picture::{Picture, Rgb, Tile};
+use libaoc::points::two_d::min_enclosing_rectangle;
+
+use crate::map::{Map, PipeDirection, Point};
+
+fn glyph(pd: PipeDirection) -> char {
+    match pd {
+        PipeDirection::NorthSouth => '│',
+        PipeDirection::EastWest => '─',
+        PipeDirection::NorthEast => '└',
+        PipeDirection::NorthWest => '┘',
+        PipeDirection::SouthWest => '┐',
+        PipeDirection::SouthEast => '┌',
+    }
+}
+
+pub fn render(file: &str) -> anyhow::Result<Picture> {
+    let map = Map::from_str(file)?;
+    let looop = map.find_loop()?;
+    let border = min_enclosing_rectangle(looop.iter(), looop.iter());
+
+    let picture = map.inner.map_with_positions(|pos, &point| {
+        let tile = match point {
+            Point::Pipe(pd) if pos == map.start_pos => Tile::new(glyph(pd), Rgb::RED),
+            Point::Pipe(pd) if looop.contains(&pos) => Tile::new(glyph(pd), Rgb::YELLOW),
+            Point::Pipe(pd) => Tile::new(glyph(pd), Rgb::GREY),
+            Point::Ground => Tile::new(' ', Rgb::GREY),
+        };
+        if map.is_inside_loop(&looop, &border, pos) {
+            tile.on(Rgb::GREEN)
+        } else {
+            tile
+        }
+    });
+    Ok(picture)
+}
@@ -0,0 +1 @@
+common::generated_input_tests!(d10::gen::input, d10::SOLUTIONS, [1, 5, 10]);
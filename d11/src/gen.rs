@@ -0,0 +1,21 @@
+//! Images of `size`×`size` pixels, with at least 2 galaxies
+
+use common::gen::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(2);
+    let mut image: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.1) { b'#' } else { b'.' })
+                .collect()
+        })
+        .collect();
+    image[0][0] = b'#';
+    image[size - 1][size - 1] = b'#';
+
+    image
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
@@ -1,14 +1,20 @@
 use core::{marker::PhantomData, str::FromStr};
 
 use anyhow::bail;
-use itertools::Itertools;
+use common::Solution;
+use grid::Grid;
 use libaoc::points::Point2D;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
+pub mod gen;
 pub mod p1;
 pub mod p2;
+pub mod render;
 
 type Pos = Point2D<usize>;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Point {
     Galaxy,
     Space,
@@ -27,8 +33,13 @@ impl TryFrom<char> for Point {
     }
 }
 
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
 struct Image<P> {
-    inner: Vec<Vec<Point>>,
+    inner: Grid<Point>,
     pub expanded_rows: Vec<usize>,
     pub expanded_cols: Vec<usize>,
     _marker: PhantomData<P>,
@@ -38,23 +49,17 @@ impl<P> FromStr for Image<P> {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let inner: Vec<Vec<_>> = s
-            .lines()
-            .map(|line| line.chars().map(Point::try_from).try_collect())
-            .try_collect()?;
+        let inner = Grid::<Point>::from_str(s)?;
         let expanded_rows: Vec<_> = inner
-            .iter()
+            .rows()
             .enumerate()
-            .filter(|(_, line)| line.iter().all(|p| matches!(p, Point::Space)))
+            .filter(|(_, row)| row.iter().all(|p| matches!(p, Point::Space)))
             .map(|(y, _)| y)
             .collect();
-        let expanded_cols = (0..inner[0].len())
-            .filter(|&x| {
-                inner
-                    .iter()
-                    .map(|row| &row[x])
-                    .all(|p| matches!(p, Point::Space))
-            })
+        let expanded_cols = inner
+            .cols()
+            .enumerate()
+            .filter_map(|(x, mut col)| col.all(|p| matches!(p, Point::Space)).then_some(x))
             .collect();
 
         Ok(Self {
@@ -68,12 +73,10 @@ impl<P> FromStr for Image<P> {
 
 impl<P> Image<P> {
     pub fn galaxies(&self) -> impl Iterator<Item = Pos> + '_ {
-        self.inner.iter().enumerate().flat_map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .filter(|(_, p)| matches!(p, Point::Galaxy))
-                .map(move |(x, _)| Point2D(x, y))
-        })
+        self.inner
+            .iter()
+            .filter(|(_, p)| matches!(p, Point::Galaxy))
+            .map(|(pos, _)| pos)
     }
 }
 
@@ -81,3 +84,29 @@ pub trait Distance {
     type Point;
     fn distance(&self, p1: Self::Point, p2: Self::Point) -> usize;
 }
+
+impl<P> Image<P>
+where
+    Self: Distance<Point = Pos> + Sync,
+{
+    /// The sum of the distances between each pair of galaxies
+    fn sum_of_distances(&self) -> usize {
+        let galaxies: Vec<_> = self.galaxies().collect();
+        #[cfg(feature = "parallel")]
+        let firsts = (0..galaxies.len()).into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let firsts = 0..galaxies.len();
+        firsts
+            .map(|i| {
+                galaxies[i + 1..]
+                    .iter()
+                    .map(|&g| self.distance(galaxies[i], g))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
+
+const DAY: u8 = 11;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
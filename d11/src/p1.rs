@@ -1,13 +1,13 @@
 use core::str::FromStr;
 
-use itertools::Itertools;
+use common::Part;
 use libaoc::points::Point2D;
 
 type Pos = Point2D<usize>;
 
 use crate::{Distance, Image};
 
-struct P1;
+pub struct P1;
 
 impl Distance for Image<P1> {
     type Point = Pos;
@@ -29,25 +29,13 @@ impl Distance for Image<P1> {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<usize> {
-    let img = Image::<P1>::from_str(file)?;
-    let res = img
-        .galaxies()
-        .combinations(2)
-        .map(|g_pair| img.distance(g_pair[0], g_pair[1]))
-        .sum();
-    Ok(res)
+    Ok(solve(&Image::<P1>::from_str(file)?))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 374)]
-    #[test_case(REAL => 9_522_407)]
-    fn test_p1(inp: &str) -> usize {
-        p1(inp).unwrap()
-    }
+fn solve(img: &Image<P1>) -> usize {
+    img.sum_of_distances()
 }
+
+common::solution!(P1, Part::One, |file| Image::<P1>::from_str(file)?, |img| {
+    solve(&img)
+});
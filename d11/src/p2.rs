@@ -1,13 +1,13 @@
 use core::str::FromStr;
 
-use itertools::Itertools;
+use common::Part;
 use libaoc::points::Point2D;
 
 type Pos = Point2D<usize>;
 
 use crate::{Distance, Image};
 
-struct P2;
+pub struct P2;
 
 const EXPANSION_RATE: usize = 1_000_000;
 
@@ -34,25 +34,13 @@ impl Distance for Image<P2> {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let img = Image::<P2>::from_str(file)?;
-    let res = img
-        .galaxies()
-        .combinations(2)
-        .map(|g_pair| img.distance(g_pair[0], g_pair[1]))
-        .sum();
-    Ok(res)
+    Ok(solve(&Image::<P2>::from_str(file)?))
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 82_000_210)]
-    #[test_case(REAL => 544_723_432_977)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
-    }
+fn solve(img: &Image<P2>) -> usize {
+    img.sum_of_distances()
 }
+
+common::solution!(P2, Part::Two, |file| Image::<P2>::from_str(file)?, |img| {
+    solve(&img)
+});
@@ -0,0 +1,25 @@
+//! The galaxies, with the rows and columns which expand shaded
+
+use core::str::FromStr;
+
+use grid::picture::{Picture, Rgb, Tile};
+use libaoc::points::Point2D;
+
+use crate::{p1::P1, Image, Point};
+
+pub fn render(file: &str) -> anyhow::Result<Picture> {
+    let image = Image::<P1>::from_str(file)?;
+
+    let picture = image.inner.map_with_positions(|Point2D(x, y), point| {
+        let tile = match point {
+            Point::Galaxy => Tile::new('#', Rgb::WHITE),
+            Point::Space => Tile::new('.', Rgb::GREY),
+        };
+        if image.expanded_rows.contains(&y) || image.expanded_cols.contains(&x) {
+            tile.on(Rgb::BLUE)
+        } else {
+            tile
+        }
+    });
+    Ok(picture)
+}
@@ -0,0 +1 @@
+common::generated_input_tests!(d11::gen::input, d11::SOLUTIONS, [1, 5, 20]);
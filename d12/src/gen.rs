@@ -0,0 +1,88 @@
+//! Condition records of `size` rows, each with a known number of possible arrangements
+
+use common::gen::{Rng, StdRng};
+
+/// At most this many springs are unknown, so that all the arrangements can be tried out
+const MAX_UNKNOWN: usize = 12;
+
+/// The lengths of the groups of damaged springs
+fn description(springs: &[u8]) -> Vec<usize> {
+    springs
+        .split(|&s| s != b'#')
+        .map(<[u8]>::len)
+        .filter(|&len| len > 0)
+        .collect()
+}
+
+/// Counts the arrangements by trying out every assignment of the unknown springs
+pub fn brute_force_arrangements(springs: &[u8], expected: &[usize]) -> usize {
+    let unknown: Vec<usize> = (0..springs.len()).filter(|&i| springs[i] == b'?').collect();
+    let mut springs = springs.to_vec();
+    (0u32..1 << unknown.len())
+        .filter(|assignment| {
+            for (bit, &i) in unknown.iter().enumerate() {
+                springs[i] = if assignment & (1 << bit) == 0 {
+                    b'.'
+                } else {
+                    b'#'
+                };
+            }
+            description(&springs) == expected
+        })
+        .count()
+}
+
+/// A row of `len` springs, and the number of its possible arrangements
+pub fn record(rng: &mut StdRng, len: usize) -> (String, usize) {
+    let len = len.max(1);
+    let mut springs: Vec<u8> = (0..len)
+        .map(|_| if rng.gen_bool(0.5) { b'#' } else { b'.' })
+        .collect();
+    // the description can't be empty
+    let broken = rng.gen_range(0..len);
+    springs[broken] = b'#';
+    let expected = description(&springs);
+
+    let mut n_unknown = 0;
+    for spring in &mut springs {
+        if n_unknown < MAX_UNKNOWN && rng.gen_bool(0.5) {
+            *spring = b'?';
+            n_unknown += 1;
+        }
+    }
+
+    let n_arrangements = brute_force_arrangements(&springs, &expected);
+    let expected: Vec<String> = expected.iter().map(usize::to_string).collect();
+    let row = format!(
+        "{} {}",
+        String::from_utf8(springs).unwrap(),
+        expected.join(",")
+    );
+    (row, n_arrangements)
+}
+
+/// Rows of at most this many springs, so that part 2 can get through them in reasonable time
+const MAX_INPUT_ROW_LEN: usize = 8;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let len = rng.gen_range(1..=MAX_INPUT_ROW_LEN);
+            record(rng, len).0 + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_arrangements() {
+        let mut rng = common::gen::rng(0);
+        for len in 1..=15 {
+            let (row, n_arrangements) = record(&mut rng, len);
+            assert_eq!(crate::p1::p1(&row).unwrap(), n_arrangements, "{row}");
+        }
+    }
+}
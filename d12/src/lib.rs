@@ -1,5 +1,12 @@
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 pub mod record;
 
 pub(crate) use record::{Record, Spring};
+
+const DAY: u8 = 12;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,31 +1,32 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
 use crate::Record;
 
+use common::Part;
 use itertools::Itertools;
 
 pub fn p1(file: &str) -> anyhow::Result<usize> {
-    let records: Vec<_> = file.lines().map(Record::from_str).try_collect()?;
+    p1_from_reader(file.as_bytes())
+}
+
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<usize> {
+    common::read::lines(reader, Record::from_str)
+        .map_ok(Record::n_possible_arrangements)
+        .sum()
+}
 
-    let res = records
+fn parse(file: &str) -> anyhow::Result<Vec<Record>> {
+    Ok(common::parse::lines(file, Record::from_str).try_collect()?)
+}
+
+fn solve(records: Vec<Record>) -> usize {
+    records
         .into_iter()
-        // .enumerate().inspect(|(i, r)| eprintln!("\nrecord #{i}: {r:?}")).map(|(_, r)| r)
         .map(Record::n_possible_arrangements)
-        // .inspect(|n| eprintln!("{n} possible arrangements"))
-        .sum();
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 21)]
-    #[test_case(REAL => 7084)]
-    fn test_p1(inp: &str) -> usize {
-        p1(inp).unwrap()
-    }
-}
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| parse(file)?, solve);
@@ -1,8 +1,12 @@
 use core::str::FromStr;
+use std::io::BufRead;
 
 use crate::{Record, Spring};
 
+use common::Part;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 impl Record {
     fn unfold(self) -> Self {
@@ -19,28 +23,31 @@ impl Record {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let records: Vec<_> = file.lines().map(Record::from_str).try_collect()?;
+    p2_from_reader(file.as_bytes())
+}
+
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<usize> {
+    common::read::lines(reader, Record::from_str)
+        .map_ok(Record::unfold)
+        .map_ok(Record::n_possible_arrangements)
+        .sum()
+}
+
+fn parse(file: &str) -> anyhow::Result<Vec<Record>> {
+    Ok(common::parse::lines(file, Record::from_str).try_collect()?)
+}
 
-    let res = records
-        .into_iter()
-        // .enumerate().inspect(|(i, r)| eprintln!("\nrecord #{i}: {r:?}")).map(|(_, r)| r)
+fn solve(records: Vec<Record>) -> usize {
+    #[cfg(feature = "parallel")]
+    let records = records.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let records = records.into_iter();
+    records
         .map(Record::unfold)
         .map(Record::n_possible_arrangements)
-        // .inspect(|n| eprintln!("{n} possible arrangements"))
-        .sum();
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 525_152)]
-    #[test_case(REAL => 0)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
-    }
-}
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| parse(file)?, solve);
@@ -1,6 +1,7 @@
 use core::{cmp::Ordering, fmt::Write, iter};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Spring {
     Broken,
     Working,
@@ -22,6 +23,7 @@ type Springs = Vec<Spring>;
 type Description = Vec<usize>;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Record {
     pub(crate) springs: Springs,
     pub(crate) description: Description,
@@ -30,6 +32,7 @@ pub(crate) struct Record {
 mod parse;
 
 impl Record {
+    #[tracing::instrument(level = "debug", ret)]
     pub fn n_possible_arrangements(self) -> usize {
         n_possible_arrangements_rec(&self.springs[..], &self.description[..])
     }
@@ -147,6 +150,8 @@ mod test {
     use core::str::FromStr;
 
     use super::*;
+    use crate::gen::brute_force_arrangements;
+    use proptest::prelude::*;
     use test_case::test_case;
 
     #[test_case("# 1", &[&[0]]; "it works")]
@@ -175,4 +180,33 @@ mod test {
 
         assert_eq!(answer.len(), cases);
     }
+
+    fn spring() -> impl Strategy<Value = Spring> {
+        prop_oneof![
+            Just(Spring::Broken),
+            Just(Spring::Working),
+            Just(Spring::Unknown),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn matches_trying_every_assignment(
+            springs in prop::collection::vec(spring(), 0..14),
+            description in prop::collection::vec(1..5usize, 0..5),
+        ) {
+            let as_text: Vec<u8> = springs
+                .iter()
+                .map(|spring| match spring {
+                    Spring::Broken => b'#',
+                    Spring::Working => b'.',
+                    Spring::Unknown => b'?',
+                })
+                .collect();
+            prop_assert_eq!(
+                n_possible_arrangements_rec(&springs, &description),
+                brute_force_arrangements(&as_text, &description)
+            );
+        }
+    }
 }
@@ -1,11 +1,13 @@
 use anyhow::bail;
-use libaoc::{impl_from_str_from_nom_parser, parse::n};
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, usize, IResult},
+};
 use nom::{
     character::complete::{anychar, char, space1},
     combinator::{map, map_res},
     multi::{many1, separated_list1},
     sequence::separated_pair,
-    IResult,
 };
 
 use super::{Description, Record, Spring, Springs};
@@ -25,24 +27,27 @@ impl TryFrom<char> for Spring {
 }
 
 fn spring(i: &str) -> IResult<&str, Spring> {
-    map_res(anychar, Spring::try_from)(i)
+    rule("spring", map_res(anychar, Spring::try_from))(i)
 }
 
 fn springs(i: &str) -> IResult<&str, Springs> {
-    many1(spring)(i)
+    rule("springs", many1(spring))(i)
 }
 
 fn description(i: &str) -> IResult<&str, Description> {
-    separated_list1(char(','), n)(i)
+    rule("description", separated_list1(char(','), usize))(i)
 }
 
 fn record(i: &str) -> IResult<&str, Record> {
-    map(
-        separated_pair(springs, space1, description),
-        |(left_half, right_half)| Record {
-            springs: left_half,
-            description: right_half,
-        },
+    rule(
+        "record",
+        map(
+            separated_pair(springs, space1, description),
+            |(left_half, right_half)| Record {
+                springs: left_half,
+                description: right_half,
+            },
+        ),
     )(i)
 }
 
@@ -0,0 +1,5 @@
+common::answer_tests!(d12::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d12::p1::p1_from_reader,
+    Two => d12::p2::p2_from_reader,
+);
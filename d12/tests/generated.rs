@@ -0,0 +1 @@
+common::generated_input_tests!(d12::gen::input, d12::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,71 @@
+//! Notes of `size` patterns
+//!
+//! Each pattern has a line of reflection for part 1, and one with exactly one smudge for part 2
+
+use common::gen::{Rng, StdRng};
+
+fn point(rng: &mut StdRng) -> u8 {
+    if rng.gen_bool(0.5) {
+        b'#'
+    } else {
+        b'.'
+    }
+}
+
+/// A pattern with a horizontal line of reflection, and a vertical one with a smudge
+fn pattern_rows(rng: &mut StdRng) -> Vec<Vec<u8>> {
+    let (height, width) = (rng.gen_range(3..=15), rng.gen_range(2..=15));
+
+    // the horizontal line mustn't be in the middle, so that some rows don't have a mirror image
+    let (row_line, rows_reflected) = loop {
+        let line: usize = rng.gen_range(1..height);
+        let reflected = line.min(height - line);
+        if 2 * reflected < height {
+            break (line, reflected);
+        }
+    };
+    let col_line = rng.gen_range(1..width);
+    let cols_reflected = col_line.min(width - col_line);
+
+    let mut rows: Vec<Vec<u8>> = (0..height)
+        .map(|_| {
+            let mut row: Vec<u8> = (0..width).map(|_| point(rng)).collect();
+            for i in 0..cols_reflected {
+                row[col_line + i] = row[col_line - 1 - i];
+            }
+            row
+        })
+        .collect();
+    for i in 0..rows_reflected {
+        let (above, below) = rows.split_at_mut(row_line);
+        below[i].clone_from(&above[row_line - 1 - i]);
+    }
+
+    // smudge a row without a mirror image, right next to the vertical line
+    let unreflected = if row_line < height - row_line {
+        rng.gen_range(2 * row_line..height)
+    } else {
+        rng.gen_range(0..height - 2 * rows_reflected)
+    };
+    let cell = &mut rows[unreflected][col_line];
+    *cell = if *cell == b'#' { b'.' } else { b'#' };
+
+    rows
+}
+
+fn pattern(rng: &mut StdRng) -> String {
+    let mut rows = pattern_rows(rng);
+    if rng.gen_bool(0.5) {
+        rows = (0..rows[0].len())
+            .map(|x| rows.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+    rows.into_iter()
+        .map(|row| String::from_utf8(row).unwrap() + "\n")
+        .collect()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size.max(1)).map(|_| pattern(rng)).collect();
+    patterns.join("\n")
+}
@@ -1,3 +1,10 @@
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 pub mod pattern;
+
+const DAY: u8 = 13;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
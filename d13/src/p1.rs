@@ -1,30 +1,27 @@
 use core::str::FromStr;
 
+use common::Part;
+
 use crate::pattern::{AreMirrorOpposites, PatternNotes, Point};
 
-struct P1;
+pub struct P1;
 
-impl AreMirrorOpposites<P1> for &[Vec<Point>] {
+impl AreMirrorOpposites<P1> for &[&[Point]] {
     fn are_mirror_opposites(self, other: Self) -> bool {
         core::iter::zip(self.iter().rev(), other).all(|(a, b)| a == b)
     }
 }
 pub fn p1(file: &str) -> anyhow::Result<usize> {
-    let notes = PatternNotes::<P1>::from_str(file)?;
-
-    Ok(notes.summarize())
+    solve(&PatternNotes::<P1>::from_str(file)?)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 405)]
-    #[test_case(REAL => 30158)]
-    fn test_p1(inp: &str) -> usize {
-        p1(inp).unwrap()
-    }
+fn solve(notes: &PatternNotes<P1>) -> anyhow::Result<usize> {
+    notes.summarize()
 }
+
+common::solution!(
+    P1,
+    Part::One,
+    |file| PatternNotes::<P1>::from_str(file)?,
+    |notes| solve(&notes)
+);
@@ -1,10 +1,12 @@
 use core::str::FromStr;
 
+use common::Part;
+
 use crate::pattern::{AreMirrorOpposites, PatternNotes, Point};
 
-struct P2;
+pub struct P2;
 
-impl AreMirrorOpposites<P2> for &[Vec<Point>] {
+impl AreMirrorOpposites<P2> for &[&[Point]] {
     fn are_mirror_opposites(self, other: Self) -> bool {
         // all the pairs of rows must be exactly the same (0 differences),
         // except for 1 pair, which may have exactly 1 difference
@@ -21,21 +23,16 @@ impl AreMirrorOpposites<P2> for &[Vec<Point>] {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let notes = PatternNotes::<P2>::from_str(file)?;
-
-    Ok(notes.summarize())
+    solve(&PatternNotes::<P2>::from_str(file)?)
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 400)]
-    #[test_case(REAL => 36474)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
-    }
+fn solve(notes: &PatternNotes<P2>) -> anyhow::Result<usize> {
+    notes.summarize()
 }
+
+common::solution!(
+    P2,
+    Part::Two,
+    |file| PatternNotes::<P2>::from_str(file)?,
+    |notes| solve(&notes)
+);
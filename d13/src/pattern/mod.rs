@@ -1,6 +1,10 @@
 use core::{fmt::Write, marker::PhantomData};
 
+use anyhow::{bail, Context};
+use grid::Grid;
+
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Point {
     Ash,
     Rocks,
@@ -17,7 +21,12 @@ impl core::fmt::Debug for Point {
 }
 
 #[derive(Debug)]
-pub(crate) struct Pattern<P>(pub(crate) Vec<Vec<Point>>, PhantomData<P>);
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub(crate) struct Pattern<P>(pub(crate) Grid<Point>, PhantomData<P>);
 
 mod parse;
 
@@ -27,49 +36,32 @@ pub(crate) enum Mirror {
     Horizontal(usize),
 }
 
-impl<P> Pattern<P> {
-    fn height(&self) -> usize {
-        self.0.len()
-    }
-    fn width(&self) -> usize {
-        self.0[0].len()
-    }
-
-    fn rows(&self) -> &Vec<Vec<Point>> {
-        &self.0
-    }
-
-    fn cols(&self) -> Vec<Vec<Point>> {
-        let width = self.width();
-        let map = self.0.clone();
-        let mut iters = map
-            .into_iter()
-            .map(IntoIterator::into_iter)
-            .collect::<Vec<_>>();
-        (0..width)
-            .map(|_| iters.iter_mut().map(|n| n.next().unwrap()).collect())
-            .collect()
-    }
+/// The line after which `rows` are mirrored, if there's one
+fn find_mirror_line<P>(rows: &[&[Point]]) -> Option<usize>
+where
+    for<'a> &'a [&'a [Point]]: AreMirrorOpposites<P>,
+{
+    (1..rows.len()).find(|&mirror_line| {
+        let (above, below) = rows.split_at(mirror_line);
+        above.are_mirror_opposites(below)
+    })
 }
 
 impl<P> Pattern<P>
 where
-    for<'a> &'a [Vec<Point>]: AreMirrorOpposites<P>,
+    for<'a> &'a [&'a [Point]]: AreMirrorOpposites<P>,
 {
-    pub(crate) fn find_mirror(&self) -> Mirror {
-        if let Some(n) = (1..self.height()).find(|&mirror_line| {
-            let (above, below) = self.rows().split_at(mirror_line);
-            above.are_mirror_opposites(below)
-        }) {
-            Mirror::Horizontal(n)
-        } else if let Some(n) = (1..self.width()).find(|&mirror_line| {
-            let cols = self.cols();
-            let (left, right) = cols.split_at(mirror_line);
-            left.are_mirror_opposites(right)
-        }) {
-            Mirror::Vertical(n)
+    pub(crate) fn find_mirror(&self) -> anyhow::Result<Mirror> {
+        let rows: Vec<_> = self.0.rows().collect();
+        let transposed = self.0.transpose();
+        let cols: Vec<_> = transposed.rows().collect();
+
+        if let Some(n) = find_mirror_line(&rows) {
+            Ok(Mirror::Horizontal(n))
+        } else if let Some(n) = find_mirror_line(&cols) {
+            Ok(Mirror::Vertical(n))
         } else {
-            panic!("mirror not found")
+            bail!("no line of reflection found")
         }
     }
 }
@@ -79,20 +71,60 @@ pub(crate) trait AreMirrorOpposites<P> {
 }
 
 #[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
 pub(crate) struct PatternNotes<P>(pub(crate) Vec<Pattern<P>>);
 
 impl<P> PatternNotes<P>
 where
-    for<'a> &'a [Vec<Point>]: AreMirrorOpposites<P>,
+    for<'a> &'a [&'a [Point]]: AreMirrorOpposites<P>,
 {
-    pub fn summarize(&self) -> usize {
+    pub fn summarize(&self) -> anyhow::Result<usize> {
         self.0
             .iter()
-            .map(Pattern::<P>::find_mirror)
-            .map(|m| match m {
-                Mirror::Vertical(n) => n,
-                Mirror::Horizontal(n) => 100 * n,
+            .enumerate()
+            .map(|(i, pattern)| {
+                let m = pattern
+                    .find_mirror()
+                    .with_context(|| format!("pattern {}", i + 1))?;
+                Ok(match m {
+                    Mirror::Vertical(n) => n,
+                    Mirror::Horizontal(n) => 100 * n,
+                })
             })
             .sum()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+    use crate::{p1::P1, p2::P2};
+    use test_case::test_case;
+
+    #[test_case("#.\n.#" => "pattern 1: no line of reflection found"; "p1")]
+    fn no_mirror_p1(input: &str) -> String {
+        let notes = PatternNotes::<P1>::from_str(input).unwrap();
+        format!("{:#}", notes.summarize().unwrap_err())
+    }
+
+    #[test_case("#.\n##\n\n##\n##" => "pattern 2: no line of reflection found"; "p2")]
+    fn no_mirror_p2(input: &str) -> String {
+        let notes = PatternNotes::<P2>::from_str(input).unwrap();
+        format!("{:#}", notes.summarize().unwrap_err())
+    }
+
+    #[test]
+    fn ragged() {
+        let Err(e) = PatternNotes::<P1>::from_str("#.#\n.#.\n\n##.\n#.") else {
+            panic!("parsed a ragged pattern");
+        };
+        assert_eq!((e.line, e.column), (4, 1));
+        assert_eq!(e.problem, "rows of a pattern must all be equally long");
+    }
+}
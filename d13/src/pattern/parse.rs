@@ -1,12 +1,14 @@
 use core::marker::PhantomData;
 
 use anyhow::bail;
+use common::parse::{parse_all, rule, separated_list1, Error, IResult, ParseError};
+use grid::Grid;
 use nom::{
     bytes::complete::tag,
     character::complete::{anychar, newline},
     combinator::{map, map_res},
-    multi::{many1, separated_list1},
-    IResult,
+    error::{ErrorKind, FromExternalError},
+    multi::many1,
 };
 
 use super::{Pattern, PatternNotes, Point};
@@ -25,33 +27,42 @@ impl TryFrom<char> for Point {
 }
 
 fn point(i: &str) -> IResult<&str, Point> {
-    map_res(anychar, Point::try_from)(i)
+    rule("point", map_res(anychar, Point::try_from))(i)
 }
 
 fn pattern<P>(i: &str) -> IResult<&str, Pattern<P>> {
-    map(separated_list1(newline, many1(point)), |p| {
-        Pattern(p, PhantomData)
+    rule("pattern", |i| {
+        let (rest, rows) = separated_list1(newline, many1(point))(i)?;
+        let Some(p) = Grid::from_rows(rows) else {
+            // NOTE: a failure rather than an error, so that the list of patterns
+            // doesn't just end before this one
+            return Err(nom::Err::Failure(Error::from_external_error(
+                i,
+                ErrorKind::Verify,
+                "rows of a pattern must all be equally long",
+            )));
+        };
+        Ok((rest, Pattern(p, PhantomData)))
     })(i)
 }
 
 impl<P> core::str::FromStr for Pattern<P> {
-    type Err = anyhow::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (remaining, object) = pattern(s).map_err(|e| anyhow::anyhow!("{e}"))?;
-        anyhow::ensure!(remaining.is_empty() || remaining == "\n");
-        Ok(object)
+        parse_all(pattern, s)
     }
 }
 
 fn pattern_notes<P>(i: &str) -> IResult<&str, PatternNotes<P>> {
-    map(separated_list1(tag("\n\n"), pattern), PatternNotes)(i)
+    rule(
+        "pattern_notes",
+        map(separated_list1(tag("\n\n"), pattern), PatternNotes),
+    )(i)
 }
 
 impl<P> core::str::FromStr for PatternNotes<P> {
-    type Err = anyhow::Error;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (remaining, object) = pattern_notes(s).map_err(|e| anyhow::anyhow!("{e}"))?;
-        anyhow::ensure!(remaining.is_empty() || remaining == "\n");
-        Ok(object)
+        parse_all(pattern_notes, s)
     }
 }
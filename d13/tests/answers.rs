@@ -0,0 +1 @@
+common::answer_tests!(d13::SOLUTIONS);
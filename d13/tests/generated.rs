@@ -0,0 +1 @@
+common::generated_input_tests!(d13::gen::input, d13::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,19 @@
+//! Platforms of `size`×`size` cells
+
+use common::gen::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| match rng.gen_range(0..20) {
+                    0..=3 => 'O',
+                    4..=6 => '#',
+                    _ => '.',
+                })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
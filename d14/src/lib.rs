@@ -1,3 +1,11 @@
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 pub mod platform;
+pub mod render;
+
+const DAY: u8 = 14;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
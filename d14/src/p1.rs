@@ -1,24 +1,19 @@
 use core::str::FromStr;
 
+use common::Part;
+
 use crate::platform::Platform;
 
 pub fn p1(file: &str) -> anyhow::Result<usize> {
-    let mut platform = Platform::from_str(file)?;
+    Ok(solve(Platform::from_str(file)?))
+}
+
+fn solve(mut platform: Platform) -> usize {
     platform.tilt_north();
 
-    Ok(platform.north_load())
+    platform.north_load()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P1;
 
-    #[test_case(EXAMPLE => 136)]
-    #[test_case(REAL => 108_840)]
-    fn test_p1(inp: &str) -> usize {
-        p1(inp).unwrap()
-    }
-}
+common::solution!(P1, Part::One, |file| Platform::from_str(file)?, solve);
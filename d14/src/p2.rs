@@ -1,69 +1,50 @@
 use core::str::FromStr;
-use std::collections::HashMap;
+
+use common::{cycle, Part};
 
 use crate::platform::Platform;
 
 const N_CYCLES: usize = 1_000_000_000;
 
+/// Tilts the platform, returning whether any of the rocks moved
+type Tilt = fn(&mut Platform) -> bool;
+
 impl Platform {
-    fn cycle(&mut self) {
-        self.tilt_north();
-        // eprintln!("north:\n{self}");
-        self.tilt_west();
-        // eprintln!("west:\n{self}");
-        self.tilt_south();
-        // eprintln!("south:\n{self}");
-        self.tilt_east();
-        // eprintln!("eaast:\n{self}\n");
+    /// Tilts the platform north, west, south and east, calling `on_tilt` after each of the tilts
+    pub(crate) fn cycle_with(&mut self, mut on_tilt: impl FnMut(&Self)) {
+        let tilts: [(&str, Tilt); 4] = [
+            ("north", Self::tilt_north),
+            ("west", Self::tilt_west),
+            ("south", Self::tilt_south),
+            ("east", Self::tilt_east),
+        ];
+        for (direction, tilt) in tilts {
+            let moved = tilt(self);
+            tracing::trace!(direction, moved, load = self.north_load(), "tilted");
+            on_tilt(self);
+        }
     }
-}
 
-pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let mut p = Platform::from_str(file)?;
-
-    let mut states = HashMap::new();
+    fn cycle(&mut self) {
+        self.cycle_with(|_| {});
+    }
 
-    let mut yet_to_run = None;
-    for i in 0..N_CYCLES {
-        // eprintln!("cycle {i}:");
+    /// The platform after one more cycle
+    pub(crate) fn cycled(&self) -> Self {
+        let mut p = self.clone();
         p.cycle();
-
-        if let Some(j) = states.insert(p.clone(), i) {
-            // eprintln!("cycle found: {j}->{i}");
-
-            // let s(i) := state after round i
-            // then:
-            // we're in this condition
-            // => s(i) = s(j)
-            // => after round j, there are repeated cycles of length (i-j)
-            // => for all k: s(j + (i-j)k) = s(i)
-            // => s(n) = s((n-j)%(i-j))
-            // => run for (N_STATES-j)%(i-j) _more_ cycles
-            // => will achieve a state equivalent to s(N_STATES)
-            yet_to_run = Some((N_CYCLES - 1 - j) % (i - j));
-            break;
-        }
+        p
     }
+}
 
-    if let Some(n) = yet_to_run {
-        for _ in 0..n {
-            p.cycle();
-        }
-    }
+pub fn p2(file: &str) -> anyhow::Result<usize> {
+    Ok(solve(Platform::from_str(file)?))
+}
 
-    Ok(p.north_load())
+fn solve(p: Platform) -> usize {
+    cycle::nth_by_key(p, Platform::cycled, Platform::fingerprint, N_CYCLES).north_load()
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P2;
 
-    #[test_case(EXAMPLE => 64)]
-    #[test_case(REAL => 103_445)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
-    }
-}
+common::solution!(P2, Part::Two, |file| Platform::from_str(file)?, solve);
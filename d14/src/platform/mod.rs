@@ -1,13 +1,17 @@
 use core::fmt::{Display, Write};
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+use grid::Grid;
+use libaoc::points::Point2D;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum MaybeRock {
     None,
     Rounded,
     Cube,
 }
 
-impl core::fmt::Debug for MaybeRock {
+impl Display for MaybeRock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let res = match self {
             Self::Cube => '#',
@@ -19,45 +23,36 @@ impl core::fmt::Debug for MaybeRock {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct Platform(pub(crate) Vec<Vec<MaybeRock>>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Platform(pub(crate) Grid<MaybeRock>);
 
 mod parse;
 
 impl Display for Platform {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in &self.0 {
-            for mr in row {
-                write!(f, "{mr:?}")?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        self.0.fmt(f)
     }
 }
 
 impl Platform {
     fn height(&self) -> usize {
-        self.0.len()
+        self.0.height()
     }
     fn width(&self) -> usize {
-        self.0[0].len()
-    }
-
-    fn rows_mut(&mut self) -> &mut Vec<Vec<MaybeRock>> {
-        &mut self.0
+        self.0.width()
     }
 
     pub(crate) fn tilt_north(&mut self) -> bool {
-        let mut changed;
+        let mut moved = false;
         loop {
-            changed = false;
+            let mut changed = false;
             for y in 1..self.height() {
                 for x in 0..self.width() {
-                    if matches!(self.0[y][x], MaybeRock::Rounded)
-                        && matches!(self.0[y - 1][x], MaybeRock::None)
+                    let (pos, above) = (Point2D(x, y), Point2D(x, y - 1));
+                    if matches!(self.0[pos], MaybeRock::Rounded)
+                        && matches!(self.0[above], MaybeRock::None)
                     {
-                        let (above, below) = self.0.split_at_mut(y);
-                        std::mem::swap(&mut above[y - 1][x], &mut below[0][x]);
+                        self.0.swap(pos, above);
                         changed = true;
                     }
                 }
@@ -65,12 +60,13 @@ impl Platform {
             if !changed {
                 break;
             }
+            moved = true;
         }
-        changed
+        moved
     }
     pub(crate) fn tilt_west(&mut self) -> bool {
         let mut changed = false;
-        for row in self.rows_mut() {
+        for row in self.0.rows_mut() {
             for part in row.split_mut(|mr| matches!(mr, MaybeRock::Cube)) {
                 // HACK: this will become a hard error once I update to 1.82.0
                 // TODO: rm the `allow` and the trait
@@ -85,16 +81,16 @@ impl Platform {
     }
 
     pub(crate) fn tilt_south(&mut self) -> bool {
-        let mut changed;
+        let mut moved = false;
         loop {
-            changed = false;
+            let mut changed = false;
             for y in 0..self.height() - 1 {
                 for x in 0..self.width() {
-                    if matches!(self.0[y][x], MaybeRock::Rounded)
-                        && matches!(self.0[y + 1][x], MaybeRock::None)
+                    let (pos, below) = (Point2D(x, y), Point2D(x, y + 1));
+                    if matches!(self.0[pos], MaybeRock::Rounded)
+                        && matches!(self.0[below], MaybeRock::None)
                     {
-                        let (above, below) = self.0.split_at_mut(y + 1);
-                        std::mem::swap(&mut above[y][x], &mut below[0][x]);
+                        self.0.swap(pos, below);
                         changed = true;
                     }
                 }
@@ -102,13 +98,14 @@ impl Platform {
             if !changed {
                 break;
             }
+            moved = true;
         }
-        changed
+        moved
     }
 
     pub(crate) fn tilt_east(&mut self) -> bool {
         let mut changed = false;
-        for row in self.rows_mut() {
+        for row in self.0.rows_mut() {
             for part in row.split_mut(|mr| matches!(mr, MaybeRock::Cube)) {
                 // HACK: this will become a hard error once I update to 1.82.0
                 // TODO: rm the `allow` and the trait
@@ -121,16 +118,23 @@ impl Platform {
         }
         changed
     }
+
+    /// Where the rounded rocks are, one bit per tile, which is all that changes between the tilts
+    pub(crate) fn fingerprint(&self) -> Vec<u64> {
+        let mut bits = vec![0; (self.width() * self.height()).div_ceil(64)];
+        for (i, &rock) in self.0.rows().flatten().enumerate() {
+            if rock == MaybeRock::Rounded {
+                bits[i / 64] |= 1 << (i % 64);
+            }
+        }
+        bits
+    }
+
     pub(crate) fn north_load(&self) -> usize {
         self.0
             .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter().map(move |mr| match mr {
-                    MaybeRock::Rounded => self.height() - y,
-                    _ => 0,
-                })
-            })
+            .filter(|(_, mr)| matches!(mr, MaybeRock::Rounded))
+            .map(|(Point2D(_, y), _)| self.height() - y)
             .sum()
     }
 }
@@ -163,3 +167,21 @@ where
         self.windows(2).all(|w| f(&w[0], &w[1]))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Platform::tilt_north; "north")]
+    #[test_case(Platform::tilt_west; "west")]
+    #[test_case(Platform::tilt_south; "south")]
+    #[test_case(Platform::tilt_east; "east")]
+    fn tilt_reports_moves(tilt: fn(&mut Platform) -> bool) {
+        let mut platform = Platform::from_str(include_str!("../../inputs/example.txt")).unwrap();
+        assert!(tilt(&mut platform), "the rocks didn't move");
+        assert!(!tilt(&mut platform), "the rocks moved again");
+    }
+}
@@ -1,12 +1,16 @@
-use anyhow::bail;
-use libaoc::impl_from_str_from_nom_parser;
+use anyhow::{bail, Context};
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
 use nom::{
     character::complete::{anychar, newline},
-    combinator::{map, map_res},
-    multi::{many1, separated_list1},
-    IResult,
+    combinator::map_res,
+    multi::many1,
 };
 
+use grid::Grid;
+
 use super::{MaybeRock, Platform};
 
 impl TryFrom<char> for MaybeRock {
@@ -24,11 +28,34 @@ impl TryFrom<char> for MaybeRock {
 }
 
 fn maybe_rock(i: &str) -> IResult<&str, MaybeRock> {
-    map_res(anychar, MaybeRock::try_from)(i)
+    rule("maybe_rock", map_res(anychar, MaybeRock::try_from))(i)
 }
 
 fn platform(i: &str) -> IResult<&str, Platform> {
-    map(separated_list1(newline, many1(maybe_rock)), Platform)(i)
+    rule(
+        "platform",
+        map_res(separated_list1(newline, many1(maybe_rock)), |rows| {
+            Grid::from_rows(rows)
+                .map(Platform)
+                .context("rows of the platform must all be equally long")
+        }),
+    )(i)
 }
 
 impl_from_str_from_nom_parser!(platform, Platform);
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn ragged() {
+        let Err(e) = Platform::from_str("O.#\n.O\n#..") else {
+            panic!("parsed a ragged platform");
+        };
+        assert_eq!((e.line, e.column), (1, 1));
+        assert_eq!(e.problem, "rows of the platform must all be equally long");
+    }
+}
@@ -0,0 +1,96 @@
+//! Where the rounded and the cube-shaped rocks are,
+//! and how they move with each tilt until they go in circles
+
+use core::str::FromStr;
+
+use common::cycle;
+use grid::picture::{Picture, Rgb, Simulation, Tile};
+
+use crate::platform::{MaybeRock, Platform};
+
+impl Platform {
+    pub(crate) fn picture(&self) -> Picture {
+        self.0.clone().map(|rock| match rock {
+            MaybeRock::Rounded => Tile::new('O', Rgb::ORANGE),
+            MaybeRock::Cube => Tile::new('#', Rgb::WHITE),
+            MaybeRock::None => Tile::new('.', Rgb::GREY),
+        })
+    }
+}
+
+pub fn render(file: &str) -> anyhow::Result<Picture> {
+    Ok(Platform::from_str(file)?.picture())
+}
+
+/// A frame for each tilt, up until the platform has gone around its cycle once
+pub fn animate(file: &str, on_frame: &mut dyn FnMut(&Picture)) -> anyhow::Result<()> {
+    let mut platform = Platform::from_str(file)?;
+    let cycle = cycle::find_by_key(platform.clone(), Platform::cycled, Platform::fingerprint);
+
+    on_frame(&platform.picture());
+    for _ in 0..cycle.start + cycle.period {
+        platform.cycle_with(|p| on_frame(&p.picture()));
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+struct Tilting {
+    platform: Platform,
+    n_tilts: usize,
+}
+
+impl Simulation for Tilting {
+    fn moves(&self) -> &'static [(char, &'static str)] {
+        &[
+            ('c', "spin cycle"),
+            ('n', "tilt north"),
+            ('w', "tilt west"),
+            ('s', "tilt south"),
+            ('e', "tilt east"),
+        ]
+    }
+
+    fn advance(&mut self, key: char) -> bool {
+        let tilt: fn(&mut Platform) -> bool = match key {
+            'c' => {
+                let mut n_tilts = 0;
+                self.platform.cycle_with(|_| n_tilts += 1);
+                self.n_tilts += n_tilts;
+                return true;
+            }
+            'n' => Platform::tilt_north,
+            'w' => Platform::tilt_west,
+            's' => Platform::tilt_south,
+            'e' => Platform::tilt_east,
+            _ => return false,
+        };
+        tilt(&mut self.platform);
+        self.n_tilts += 1;
+        true
+    }
+
+    fn picture(&self) -> Picture {
+        self.platform.picture()
+    }
+
+    fn status(&self) -> String {
+        format!(
+            "tilts: {}, load on the north beams: {}",
+            self.n_tilts,
+            self.platform.north_load()
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// Tilting the platform any which way
+pub fn simulate(file: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    Ok(Box::new(Tilting {
+        platform: Platform::from_str(file)?,
+        n_tilts: 0,
+    }))
+}
@@ -0,0 +1 @@
+common::generated_input_tests!(d14::gen::input, d14::SOLUTIONS, [1, 5, 20]);
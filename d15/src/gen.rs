@@ -0,0 +1,27 @@
+//! Initialization sequences of `size` steps
+//!
+//! The labels are drawn from a small pool, so that lenses actually get replaced and removed
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+fn label(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(1..=6))
+        .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+        .collect()
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size.div_ceil(3)).map(|_| label(rng)).collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = labels.choose(rng).unwrap();
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
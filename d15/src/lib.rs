@@ -1,3 +1,6 @@
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 pub mod step;
@@ -7,3 +10,7 @@ fn hash(s: &str) -> u32 {
         .map(|c| c as u32)
         .fold(0, |hash, c| ((hash + c) * 17) % 256)
 }
+
+const DAY: u8 = 15;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,19 +1,40 @@
+use std::io::BufRead;
+
+use common::Part;
+
 use crate::hash;
 
-pub fn p1(file: &str) -> u32 {
-    file.strip_suffix('\n').unwrap().split(',').map(hash).sum()
+pub fn p1(file: &str) -> anyhow::Result<u32> {
+    p1_from_reader(file.as_bytes())
+}
+
+pub fn p1_from_reader(reader: impl BufRead) -> anyhow::Result<u32> {
+    common::read::split(reader, b',')
+        .map(|step| Ok(hash(&step?)))
+        .sum()
+}
+
+/// The steps, as they are hashed
+fn parse(file: &str) -> anyhow::Result<Vec<String>> {
+    common::read::split(file.as_bytes(), b',').collect()
+}
+
+fn solve(steps: &[String]) -> u32 {
+    steps.iter().map(|step| hash(step)).sum()
 }
 
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| parse(file)?, |steps| solve(&steps));
+
 #[cfg(test)]
 mod test {
     use super::*;
     use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
 
-    #[test_case(EXAMPLE => 1320)]
-    #[test_case(REAL => 515_495)]
+    #[test_case("rn=1,cm-\n" => 283; "trailing newline")]
+    #[test_case("rn=1,cm-" => 283; "no trailing newline")]
     fn test_p1(inp: &str) -> u32 {
-        p1(inp)
+        p1(inp).unwrap()
     }
 }
@@ -1,29 +1,35 @@
+use std::io::BufRead;
+
 use crate::{
     hash,
-    step::{Operation, Step},
+    step::{steps, Operation, Step},
 };
 
-use itertools::Itertools;
+use anyhow::Context;
+use common::{parse::parse_all, Part};
 
 const N_BOXES: usize = 256;
 
-struct Lens<'a> {
-    label: &'a str,
+/// The label is borrowed from the whole input, or owned when the steps are read one by one
+struct Lens<L> {
+    label: L,
     focal_length: u32,
 }
 
-pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let steps: Vec<_> = (file.strip_suffix('\n').unwrap())
-        .split(',')
-        .map(Step::try_from)
-        .try_collect()?;
+struct Boxes<L>([Vec<Lens<L>>; N_BOXES]);
 
-    let mut boxes: [Vec<Lens>; N_BOXES] = core::array::from_fn(|_| vec![]);
+impl<L> Boxes<L> {
+    fn new() -> Self {
+        Self(core::array::from_fn(|_| vec![]))
+    }
 
-    for step in steps {
+    fn apply<'a>(&mut self, step: Step<'a>)
+    where
+        L: From<&'a str> + PartialEq<&'a str>,
+    {
         let Step { label, operation } = step;
         let box_i = hash(label);
-        let boks = &mut boxes[box_i as usize];
+        let boks = &mut self.0[box_i as usize];
 
         match operation {
             Operation::Remove => {
@@ -34,36 +40,51 @@ pub fn p2(file: &str) -> anyhow::Result<usize> {
             Operation::Insert(focal_length) => match boks.iter().position(|l| l.label == label) {
                 Some(i) => boks[i].focal_length = focal_length,
                 None => boks.push(Lens {
-                    label,
+                    label: L::from(label),
                     focal_length,
                 }),
             },
         }
     }
 
-    let res = boxes
-        .into_iter()
-        .enumerate()
-        .flat_map(|(box_i, boks)| {
-            boks.into_iter()
-                .enumerate()
-                .map(move |(lens_i, lens)| (box_i + 1) * (lens_i + 1) * lens.focal_length as usize)
-        })
-        .sum();
+    fn focusing_power(self) -> usize {
+        self.0
+            .into_iter()
+            .enumerate()
+            .flat_map(|(box_i, boks)| {
+                boks.into_iter().enumerate().map(move |(lens_i, lens)| {
+                    (box_i + 1) * (lens_i + 1) * lens.focal_length as usize
+                })
+            })
+            .sum()
+    }
+}
+
+pub fn p2(file: &str) -> anyhow::Result<usize> {
+    p2_from_reader(file.as_bytes())
+}
 
-    Ok(res)
+pub fn p2_from_reader(reader: impl BufRead) -> anyhow::Result<usize> {
+    let mut boxes = Boxes::<String>::new();
+    for (i, step) in common::read::split(reader, b',').enumerate() {
+        let step = step?;
+        boxes.apply(Step::try_from(step.as_str()).with_context(|| format!("step {}", i + 1))?);
+    }
+    Ok(boxes.focusing_power())
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+fn parse(file: &str) -> anyhow::Result<Vec<Step<'_>>> {
+    Ok(parse_all(steps, file)?)
+}
 
-    #[test_case(EXAMPLE => 145)]
-    #[test_case(REAL => 229_349)]
-    fn test_p2(inp: &str) -> usize {
-        p2(inp).unwrap()
+fn solve(steps: Vec<Step>) -> usize {
+    let mut boxes = Boxes::<&str>::new();
+    for step in steps {
+        boxes.apply(step);
     }
+    boxes.focusing_power()
 }
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| parse(file)?, solve);
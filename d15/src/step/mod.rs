@@ -1,10 +1,14 @@
 mod parse;
 
+pub(crate) use parse::steps;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum Operation {
     Remove,
     Insert(u32),
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Step<'a> {
     pub(crate) label: &'a str,
     pub(crate) operation: Operation,
@@ -0,0 +1,5 @@
+common::answer_tests!(d15::SOLUTIONS);
+common::reader_answer_tests!(
+    One => d15::p1::p1_from_reader,
+    Two => d15::p2::p2_from_reader,
+);
@@ -0,0 +1 @@
+common::generated_input_tests!(d15::gen::input, d15::SOLUTIONS, [1, 5, 20]);
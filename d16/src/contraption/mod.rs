@@ -1,13 +1,15 @@
 use std::collections::HashSet;
 
-use libaoc::{map::Map2D, points::Point2D};
+use grid::{Grid, Pos};
+use libaoc::points::Point2D;
 
-type Pos = Point2D<usize>;
+pub(crate) use grid::Direction;
 
 mod parse;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum MaybeMirror {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum MaybeMirror {
     Not,
     Slash,
     Backslash,
@@ -15,21 +17,22 @@ enum MaybeMirror {
     Vertical,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) enum Direction {
-    Up,
-    Right,
-    Down,
-    Left,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(crate) struct Beam {
     pub(crate) pos: Pos,
     pub(crate) direction: Direction,
 }
 
-pub(crate) struct Contraption(Map2D<MaybeMirror>);
+impl Beam {
+    /// The beam entering the top-left corner heading right
+    pub(crate) const START: Self = Self {
+        pos: Point2D(0, 0),
+        direction: Direction::Right,
+    };
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Contraption(pub(crate) Grid<MaybeMirror>);
 
 impl Contraption {
     pub(crate) fn width(&self) -> usize {
@@ -39,40 +42,50 @@ impl Contraption {
         self.0.height()
     }
     pub(crate) fn shine(&self) -> usize {
-        let b = Beam {
-            pos: Point2D::default(),
-            direction: Direction::Right,
-        };
-        self.shine_from(b)
+        self.shine_from(Beam::START)
     }
 
     pub(crate) fn shine_from(&self, start_beam: Beam) -> usize {
-        let try_move = |beam: Beam, move_direction| {
-            let Point2D(x, y) = beam.pos;
-
-            let pos = match move_direction {
-                Direction::Left => (x > 0).then(|| Point2D(x - 1, y)),
-                Direction::Up => (y > 0).then(|| Point2D(x, y - 1)),
-                Direction::Right => (x + 1 < self.width()).then_some(Point2D(x + 1, y)),
-                Direction::Down => (y + 1 < self.height()).then_some(Point2D(x, y + 1)),
-            };
-
-            pos.map(|pos| Beam {
-                pos,
-                direction: move_direction,
-            })
+        let energized: HashSet<_> = self.beams_from(start_beam).iter().map(|b| b.pos).collect();
+        energized.len()
+    }
+
+    /// All the beams that go through the contraption when `start_beam` is shone into it
+    pub(crate) fn beams_from(&self, start_beam: Beam) -> HashSet<Beam> {
+        self.beams_from_with(start_beam, |_, _| {})
+    }
+
+    /// Like [`Contraption::beams_from`], but calls `on_generation` with the front of the beam
+    /// and all the beams so far each time the front moves one tile further
+    pub(crate) fn beams_from_with(
+        &self,
+        start_beam: Beam,
+        mut on_generation: impl FnMut(&[Beam], &HashSet<Beam>),
+    ) -> HashSet<Beam> {
+        let mut light = Light::new(start_beam);
+        while !light.front.is_empty() {
+            on_generation(&light.front, &light.beams);
+            self.spread(&mut light);
+        }
+        light.beams
+    }
+
+    /// Moves the front of `light` one tile further
+    pub(crate) fn spread(&self, light: &mut Light) {
+        let try_move = |beam: Beam, direction| {
+            self.0
+                .step(beam.pos, direction)
+                .map(|pos| Beam { pos, direction })
         };
 
-        let mut beams = vec![start_beam];
-        let mut visited = HashSet::from([start_beam]);
-        while !beams.is_empty() {
-            let mut new_beams = vec![];
+        let mut new_beams = vec![];
 
-            // eprintln!("beams: {beams:?}");
+        tracing::trace!(front = ?light.front, "spreading");
 
-            for beam in beams {
-                let Beam { pos, direction } = beam;
-                let new_directions: &[Direction] = match (direction, self.0[pos]) {
+        for beam in light.front.drain(..) {
+            let Beam { pos, direction } = beam;
+            let new_directions: &[Direction] =
+                match (direction, self.0[pos]) {
                     // continue straight
                     (_, MaybeMirror::Not)
                     | (Direction::Right | Direction::Left, MaybeMirror::Horizontal)
@@ -100,44 +113,37 @@ impl Contraption {
                     }
                 };
 
-                for direction in new_directions {
-                    if let Some(new_beam) = try_move(beam, *direction) {
-                        new_beams.push(new_beam);
-                    }
+            for direction in new_directions {
+                if let Some(new_beam) = try_move(beam, *direction) {
+                    new_beams.push(new_beam);
                 }
             }
+        }
 
-            let new_beams = new_beams
-                .into_iter()
-                .filter(|b| !visited.contains(b))
-                .collect();
+        let new_beams = new_beams
+            .into_iter()
+            .filter(|b| !light.beams.contains(b))
+            .collect();
 
-            // eprintln!("new beams: {new_beams:?}\n");
-            beams = new_beams;
+        tracing::trace!(?new_beams, "spread");
+        light.front = new_beams;
 
-            visited.extend(beams.clone());
-        }
+        light.beams.extend(light.front.clone());
+    }
+}
 
-        /*
-        let mut v = vec![vec![false; self.0.width()]; self.0.height()];
-        for &Beam {
-            pos: Point2D(x, y), ..
-        } in &visited
-        {
-            v[y][x] = true;
-        }
+/// The beams of light in a contraption, with the ones at the front of it yet to move further
+#[derive(Debug, Clone)]
+pub(crate) struct Light {
+    pub(crate) front: Vec<Beam>,
+    pub(crate) beams: HashSet<Beam>,
+}
 
-        for row in v {
-            for c in row {
-                eprint!("{}", if c { '#' } else { '.' });
-            }
-            eprintln!();
+impl Light {
+    pub(crate) fn new(start_beam: Beam) -> Self {
+        Self {
+            front: vec![start_beam],
+            beams: HashSet::from([start_beam]),
         }
-
-        eprintln!("{visited:?}");
-        */
-
-        let visited: HashSet<_> = visited.into_iter().map(|b| b.pos).collect();
-        visited.len()
     }
 }
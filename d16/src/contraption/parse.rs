@@ -1,6 +1,6 @@
 use core::str::FromStr;
 
-use libaoc::map::Map2D;
+use grid::Grid;
 
 use super::{Contraption, MaybeMirror};
 
@@ -22,8 +22,6 @@ impl FromStr for Contraption {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let map = Map2D::from_str(s)?;
-
-        Ok(Self(map))
+        Ok(Self(Grid::parse(s, MaybeMirror::try_from)?))
     }
 }
@@ -0,0 +1,23 @@
+//! Contraptions of `size`×`size` tiles
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const DEVICES: &[char] = &['/', '\\', '|', '-'];
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| {
+                    if rng.gen_bool(0.2) {
+                        *DEVICES.choose(rng).unwrap()
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
@@ -1,16 +1,21 @@
 use core::str::FromStr;
 
+use common::Part;
 use libaoc::points::Point2D;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::contraption::{Beam, Contraption, Direction};
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let c = Contraption::from_str(file)?;
+    Ok(solve(&Contraption::from_str(file)?))
+}
 
+fn solve(c: &Contraption) -> usize {
     let w = c.width();
     let h = c.height();
 
-    let res = (0..w)
+    let starts: Vec<_> = (0..w)
         .flat_map(|x| {
             [
                 Beam {
@@ -18,7 +23,7 @@ pub fn p2(file: &str) -> anyhow::Result<usize> {
                     direction: Direction::Down,
                 },
                 Beam {
-                    pos: Point2D(x, w - 1),
+                    pos: Point2D(x, h - 1),
                     direction: Direction::Up,
                 },
             ]
@@ -30,27 +35,34 @@ pub fn p2(file: &str) -> anyhow::Result<usize> {
                     direction: Direction::Right,
                 },
                 Beam {
-                    pos: Point2D(h - 1, y),
+                    pos: Point2D(w - 1, y),
                     direction: Direction::Left,
                 },
             ]
         }))
-        .map(|b| c.shine_from(b))
-        .max()
-        .unwrap_or_default();
+        .collect();
 
-    Ok(res)
+    #[cfg(feature = "parallel")]
+    let starts = starts.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let starts = starts.into_iter();
+    starts.map(|b| c.shine_from(b)).max().unwrap_or_default()
 }
 
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Contraption::from_str(file)?, |c| {
+    solve(&c)
+});
+
 #[cfg(test)]
 mod test {
+    use super::*;
     use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
 
-    #[test_case(EXAMPLE => 51)]
-    #[test_case(REAL => 0)]
-    fn p2(inp: &str) -> usize {
-        super::p2(inp).unwrap()
+    #[test_case("..\\.-.\n.|...." => 6; "wide")]
+    #[test_case("..\n\\.\n..\n.|\n-.\n.." => 7; "tall")]
+    fn non_square(file: &str) -> usize {
+        p2(file).unwrap()
     }
 }
@@ -0,0 +1,111 @@
+//! The tiles energized by the beam shone into the top-left corner,
+//! with the directions the beam goes through the empty ones in
+
+use core::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use grid::picture::{Picture, Rgb, Simulation, Tile};
+
+use crate::contraption::{Beam, Contraption, Light, MaybeMirror};
+
+/// The contraption with the tiles `beams` go through energized, and those of `front` highlighted
+fn picture(contraption: &Contraption, beams: &HashSet<Beam>, front: &[Beam]) -> Picture {
+    let mut directions = HashMap::<_, Vec<_>>::new();
+    for &Beam { pos, direction } in beams {
+        directions.entry(pos).or_default().push(direction);
+    }
+    let front: HashSet<_> = front.iter().map(|b| b.pos).collect();
+
+    let energized = Rgb::BLACK.mix(Rgb::ORANGE, 0.5);
+    contraption.0.map_with_positions(|pos, mirror| {
+        let glyph = match (mirror, directions.get(&pos).map(Vec::as_slice)) {
+            (MaybeMirror::Not, None) => '.',
+            (MaybeMirror::Not, Some([direction])) => direction.arrow(),
+            (MaybeMirror::Not, Some(directions)) => {
+                char::from_digit(directions.len() as u32, 10).unwrap_or('*')
+            }
+            (MaybeMirror::Slash, _) => '/',
+            (MaybeMirror::Backslash, _) => '\\',
+            (MaybeMirror::Horizontal, _) => '-',
+            (MaybeMirror::Vertical, _) => '|',
+        };
+        if front.contains(&pos) {
+            return Tile::new(glyph, Rgb::BLACK).on(Rgb::YELLOW);
+        }
+        match (mirror, directions.contains_key(&pos)) {
+            (MaybeMirror::Not, false) => Tile::new(glyph, Rgb::GREY),
+            (MaybeMirror::Not, true) => Tile::new(glyph, Rgb::YELLOW).on(energized),
+            (_, false) => Tile::new(glyph, Rgb::WHITE),
+            (_, true) => Tile::new(glyph, Rgb::WHITE).on(energized),
+        }
+    })
+}
+
+pub fn render(file: &str) -> anyhow::Result<Picture> {
+    let contraption = Contraption::from_str(file)?;
+    let beams = contraption.beams_from(Beam::START);
+    Ok(picture(&contraption, &beams, &[]))
+}
+
+/// A frame for each step of the front of the beam
+pub fn animate(file: &str, on_frame: &mut dyn FnMut(&Picture)) -> anyhow::Result<()> {
+    let contraption = Contraption::from_str(file)?;
+
+    let beams = contraption.beams_from_with(Beam::START, |front, beams| {
+        on_frame(&picture(&contraption, beams, front));
+    });
+    on_frame(&picture(&contraption, &beams, &[]));
+    Ok(())
+}
+
+#[derive(Clone)]
+struct Spreading {
+    contraption: Rc<Contraption>,
+    light: Light,
+    generation: usize,
+}
+
+impl Simulation for Spreading {
+    fn moves(&self) -> &'static [(char, &'static str)] {
+        &[('n', "move the front of the beam")]
+    }
+
+    fn advance(&mut self, key: char) -> bool {
+        if key != 'n' || self.light.front.is_empty() {
+            return false;
+        }
+        self.contraption.spread(&mut self.light);
+        self.generation += 1;
+        true
+    }
+
+    fn picture(&self) -> Picture {
+        picture(&self.contraption, &self.light.beams, &self.light.front)
+    }
+
+    fn status(&self) -> String {
+        let energized: HashSet<_> = self.light.beams.iter().map(|b| b.pos).collect();
+        format!(
+            "generation: {}, beams at the front: {}, tiles energized: {}",
+            self.generation,
+            self.light.front.len(),
+            energized.len()
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// The front of the beam moving one tile at a time
+pub fn simulate(file: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    Ok(Box::new(Spreading {
+        contraption: Rc::new(Contraption::from_str(file)?),
+        light: Light::new(Beam::START),
+        generation: 0,
+    }))
+}
@@ -0,0 +1 @@
+common::generated_input_tests!(d16::gen::input, d16::SOLUTIONS, [1, 5, 20]);
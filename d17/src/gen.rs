@@ -0,0 +1,15 @@
+//! Maps of `size`×`size` city blocks, at least 5×5 so that the ultra crucibles can reach the end
+
+use common::gen::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(5);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| char::from(rng.gen_range(b'1'..=b'9')))
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
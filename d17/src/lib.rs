@@ -1,7 +1,15 @@
-use libaoc::map::Map2D;
+use common::Solution;
+use grid::Grid;
 
+pub mod gen;
 pub mod p1;
 pub mod p2;
 mod parse;
+pub mod render;
 
-struct Map(Map2D<u32>);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Map(Grid<u32>);
+
+const DAY: u8 = 17;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
@@ -1,21 +1,18 @@
 use core::str::FromStr;
 
 use anyhow::Context;
-use libaoc::{
-    direction::Direction4 as Direction,
-    points::{ManhattanDistance, Point2D},
-};
+use common::Part;
+use grid::{Direction, Pos};
+use libaoc::points::{ManhattanDistance, Point2D};
 use pathfinding::directed::astar;
 
 use crate::Map;
 
-type Pos = Point2D<usize>;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Crucible {
-    pos: Pos,
-    direction: Direction,
-    moves_since_turn: u8,
+pub(crate) struct Crucible {
+    pub(crate) pos: Pos,
+    pub(crate) direction: Direction,
+    pub(crate) moves_since_turn: u8,
 }
 
 const MAX_MOVES_TILL_TURN: u8 = 3;
@@ -44,7 +41,7 @@ impl Crucible {
 
         // turn
         for d in [direction.turn_right(), direction.turn_left()] {
-            if let Some(p) = map.0.try_go(pos, d) {
+            if let Some(p) = map.0.step(pos, d) {
                 let cost = map.0[p] as usize;
                 successors.push((Crucible::new(p, d, 1), cost));
             }
@@ -52,51 +49,54 @@ impl Crucible {
 
         // continue straight
         if moves_since_turn < MAX_MOVES_TILL_TURN {
-            if let Some(p) = map.0.try_go(pos, direction) {
+            if let Some(p) = map.0.step(pos, direction) {
                 let cost = map.0[p] as usize;
                 successors.push((Crucible::new(p, direction, moves_since_turn + 1), cost));
             }
         }
 
-        // eprintln!("\nsucc({self:?}):");
-        // for s in &successors {
-        //     eprintln!(" {s:?}");
-        // }
+        tracing::trace!(crucible = ?self, ?successors, "expanding");
 
         successors
     }
 }
 
 pub fn p1(file: &str) -> anyhow::Result<usize> {
-    let map = Map::from_str(file)?;
+    solve(&Map::from_str(file)?)
+}
+
+/// The path with the least heat loss, along with that heat loss
+pub(crate) fn best_path(map: &Map) -> anyhow::Result<(Vec<Crucible>, usize)> {
+    best_path_with(map, |_| {})
+}
 
+/// Like [`best_path`], but calls `on_expand` with each crucible the search looks further from
+pub(crate) fn best_path_with(
+    map: &Map,
+    mut on_expand: impl FnMut(&Crucible),
+) -> anyhow::Result<(Vec<Crucible>, usize)> {
     let start = Crucible::new(Pos::default(), Direction::Right, MAX_MOVES_TILL_TURN);
     let dest_pos = Point2D(map.0.width() - 1, map.0.height() - 1);
 
-    let (_path, cost) = astar::astar(
+    astar::astar(
         &start,
-        |c| c.successors(&map),
+        |c| {
+            on_expand(c);
+            c.successors(map)
+        },
         |c| c.pos.manhattan_distance(dest_pos),
         |c| c.pos == dest_pos,
     )
-    .context("no path")?;
-
-    // for step in _path {
-    //     eprintln!("{step:?}");
-    // }
+    .context("no path")
+}
 
+fn solve(map: &Map) -> anyhow::Result<usize> {
+    let (_path, cost) = best_path(map)?;
     Ok(cost)
 }
 
-#[cfg(test)]
-mod test {
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P1;
 
-    #[test_case(EXAMPLE => 102)]
-    #[test_case(REAL => 851)]
-    fn p1(inp: &str) -> usize {
-        super::p1(inp).unwrap()
-    }
-}
+common::solution!(P1, Part::One, |file| Map::from_str(file)?, |map| {
+    solve(&map)
+});
@@ -1,21 +1,18 @@
 use core::str::FromStr;
 
 use anyhow::Context;
-use libaoc::{
-    direction::Direction4 as Direction,
-    points::{ManhattanDistance, Point2D},
-};
+use common::Part;
+use grid::{Direction, Pos};
+use libaoc::points::{ManhattanDistance, Point2D};
 use pathfinding::directed::astar;
 
 use crate::Map;
 
-type Pos = Point2D<usize>;
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct UltraCrucible {
-    pos: Pos,
-    direction: Direction,
-    moves_since_turn: u8,
+pub(crate) struct UltraCrucible {
+    pub(crate) pos: Pos,
+    pub(crate) direction: Direction,
+    pub(crate) moves_since_turn: u8,
 }
 
 const MAX_MOVES_TILL_TURN: u8 = 10;
@@ -46,7 +43,7 @@ impl UltraCrucible {
         // turn
         if moves_since_turn >= MIN_MOVES_AFTER_TURN {
             for d in [direction.turn_right(), direction.turn_left()] {
-                if let Some(p) = map.0.try_go(pos, d) {
+                if let Some(p) = map.0.step(pos, d) {
                     let cost = map.0[p] as usize;
                     successors.push((UltraCrucible::new(p, d, 1), cost));
                 }
@@ -55,47 +52,43 @@ impl UltraCrucible {
 
         // continue straight
         if moves_since_turn < MAX_MOVES_TILL_TURN {
-            if let Some(p) = map.0.try_go(pos, direction) {
+            if let Some(p) = map.0.step(pos, direction) {
                 let cost = map.0[p] as usize;
                 successors.push((UltraCrucible::new(p, direction, moves_since_turn + 1), cost));
             }
         }
 
-        // eprintln!("\nsucc({self:?}):");
-        // for s in &successors {
-        //     eprintln!(" {s:?}");
-        // }
+        tracing::trace!(crucible = ?self, ?successors, "expanding");
 
         successors
     }
 }
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
-    let map = Map::from_str(file)?;
+    solve(&Map::from_str(file)?)
+}
 
+/// The path with the least heat loss, along with that heat loss
+pub(crate) fn best_path(map: &Map) -> anyhow::Result<(Vec<UltraCrucible>, usize)> {
     let start = UltraCrucible::new(Pos::default(), Direction::Right, 0);
     let dest_pos = Point2D(map.0.width() - 1, map.0.height() - 1);
 
-    let (_path, cost) = astar::astar(
+    astar::astar(
         &start,
-        |c| c.successors(&map),
+        |c| c.successors(map),
         |c| c.pos.manhattan_distance(dest_pos),
         |c| c.pos == dest_pos,
     )
-    .context("no path")?;
+    .context("no path")
+}
 
+fn solve(map: &Map) -> anyhow::Result<usize> {
+    let (_path, cost) = best_path(map)?;
     Ok(cost)
 }
 
-#[cfg(test)]
-mod test {
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P2;
 
-    #[test_case(EXAMPLE => 94)]
-    #[test_case(REAL => 982)]
-    fn p2(inp: &str) -> usize {
-        super::p2(inp).unwrap()
-    }
-}
+common::solution!(P2, Part::Two, |file| Map::from_str(file)?, |map| {
+    solve(&map)
+});
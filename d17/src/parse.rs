@@ -1,8 +1,7 @@
 use core::str::FromStr;
 
 use anyhow::Context;
-use itertools::Itertools;
-use libaoc::map::Map2D;
+use grid::Grid;
 
 use crate::Map;
 
@@ -10,19 +9,28 @@ impl FromStr for Map {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let v = s
-            .lines()
-            .map(|row| {
-                row.chars()
-                    .map(|c| {
-                        c.to_digit(10)
-                            .with_context(|| format!("invalid heat loss value: {c}"))
-                    })
-                    .try_collect()
-            })
-            .try_collect()
-            .unwrap();
+        let grid = Grid::parse(s, |c| {
+            c.to_digit(10)
+                .with_context(|| format!("invalid heat loss value: {c}"))
+        })?;
 
-        Ok(Self(Map2D::new(v)))
+        Ok(Self(grid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use common::parse;
+    use test_case::test_case;
+
+    #[test_case("12\n3x" => "invalid heat loss value: x"; "not a digit")]
+    #[test_case("12\n3" => "expected a row of 2 cells, found 1"; "ragged")]
+    #[test_case("" => "empty grid"; "empty")]
+    fn invalid(input: &str) -> String {
+        let Err(e) = Map::from_str(input) else {
+            panic!("parsed invalid input");
+        };
+        e.downcast::<parse::ParseError>().unwrap().problem
     }
 }
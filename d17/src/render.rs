@@ -0,0 +1,145 @@
+//! The path of the crucible of part 1 over the heat map,
+//! and how the search for it spreads over the map
+
+use core::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use grid::{
+    picture::{Picture, Rgb, Simulation, Tile},
+    Direction, Pos,
+};
+
+use crate::{
+    p1::{self, best_path, best_path_with},
+    p2, Map,
+};
+
+/// The heat map with the tiles the search has `explored` tinted, and the `path` drawn over it
+fn picture(map: &Map, explored: &HashSet<Pos>, path: &HashMap<Pos, Direction>) -> Picture {
+    map.0.map_with_positions(|pos, &heat_loss| {
+        let mut heat = Rgb::BLACK.mix(Rgb::RED, f64::from(heat_loss) / 9.0);
+        if explored.contains(&pos) {
+            heat = heat.mix(Rgb::BLUE, 0.5);
+        }
+        let tile = match path.get(&pos) {
+            Some(direction) => Tile::new(direction.arrow(), Rgb::WHITE),
+            None => Tile::new(char::from_digit(heat_loss, 10).unwrap_or('?'), Rgb::GREY),
+        };
+        tile.on(heat)
+    })
+}
+
+pub fn render(file: &str) -> anyhow::Result<Picture> {
+    let map = Map::from_str(file)?;
+    let (path, _) = best_path(&map)?;
+    let path = path.iter().map(|c| (c.pos, c.direction)).collect();
+    Ok(picture(&map, &HashSet::new(), &path))
+}
+
+/// A frame every so many crucibles the search looks further from, and then one with the path
+pub fn animate(file: &str, on_frame: &mut dyn FnMut(&Picture)) -> anyhow::Result<()> {
+    let map = Map::from_str(file)?;
+    // 8 frames for each time the search could have gone over every tile
+    let every = (map.0.width() * map.0.height() / 8).max(1);
+
+    let mut explored = HashSet::new();
+    let mut n_expanded = 0;
+    let (path, _) = best_path_with(&map, |c| {
+        explored.insert(c.pos);
+        n_expanded += 1;
+        if n_expanded % every == 0 {
+            on_frame(&picture(&map, &explored, &HashMap::new()));
+        }
+    })?;
+
+    let path = path.iter().map(|c| (c.pos, c.direction)).collect();
+    on_frame(&picture(&map, &explored, &path));
+    Ok(())
+}
+
+/// Where a crucible is, where it's heading, and how many moves it has made since it last turned
+type Step = (Pos, Direction, u8);
+
+#[derive(Clone)]
+struct Walking {
+    map: Rc<Map>,
+    path: Rc<[Step]>,
+    i: usize,
+}
+
+impl Simulation for Walking {
+    fn moves(&self) -> &'static [(char, &'static str)] {
+        &[('n', "move the crucible")]
+    }
+
+    fn advance(&mut self, key: char) -> bool {
+        if key != 'n' || self.i + 1 == self.path.len() {
+            return false;
+        }
+        self.i += 1;
+        true
+    }
+
+    fn picture(&self) -> Picture {
+        let path = self.path[..=self.i]
+            .iter()
+            .map(|&(pos, direction, _)| (pos, direction))
+            .collect();
+        let mut picture = picture(&self.map, &HashSet::new(), &path);
+        let (pos, direction, _) = self.path[self.i];
+        picture[pos] = Tile::new(direction.arrow(), Rgb::BLACK).on(Rgb::YELLOW);
+        picture
+    }
+
+    fn status(&self) -> String {
+        let (pos, direction, moves_since_turn) = self.path[self.i];
+        // turning around at the start doesn't lose any heat
+        let heat_loss: u32 = self.path[..=self.i]
+            .windows(2)
+            .filter(|w| w[0].0 != w[1].0)
+            .map(|w| self.map.0[w[1].0])
+            .sum();
+        format!(
+            "step {} of {}: at {pos:?} heading {direction:?}, moves since turning: {moves_since_turn}, heat lost: {heat_loss}",
+            self.i,
+            self.path.len() - 1,
+        )
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Simulation> {
+        Box::new(self.clone())
+    }
+}
+
+/// The crucible of part 1 going along its path
+pub fn simulate_p1(file: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let map = Map::from_str(file)?;
+    let (path, _) = best_path(&map)?;
+    let path = path
+        .iter()
+        .map(|c: &p1::Crucible| (c.pos, c.direction, c.moves_since_turn))
+        .collect();
+    Ok(Box::new(Walking {
+        map: Rc::new(map),
+        path,
+        i: 0,
+    }))
+}
+
+/// The ultra crucible of part 2 going along its path
+pub fn simulate_p2(file: &str) -> anyhow::Result<Box<dyn Simulation>> {
+    let map = Map::from_str(file)?;
+    let (path, _) = p2::best_path(&map)?;
+    let path = path
+        .iter()
+        .map(|c| (c.pos, c.direction, c.moves_since_turn))
+        .collect();
+    Ok(Box::new(Walking {
+        map: Rc::new(map),
+        path,
+        i: 0,
+    }))
+}
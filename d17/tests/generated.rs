@@ -0,0 +1 @@
+common::generated_input_tests!(d17::gen::input, d17::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,57 @@
+//! Dig plans of `size` columns of different heights, side by side
+//!
+//! The trench goes along the bottom, and then back over the tops of the columns,
+//! so it never crosses itself. The colours hide another such plan, with the longer trenches of part 2
+
+use core::fmt::Write;
+
+use common::gen::{Rng, StdRng};
+
+/// Goes around `n_columns` columns at most `max_metres` wide and high, clockwise from the bottom left
+fn columns(rng: &mut StdRng, n_columns: usize, max_metres: u32) -> Vec<(char, u32)> {
+    let widths: Vec<u32> = (0..n_columns)
+        .map(|_| rng.gen_range(1..=max_metres))
+        .collect();
+    let mut heights: Vec<u32> = vec![];
+    while heights.len() < n_columns {
+        let height = rng.gen_range(1..=max_metres);
+        // the neighbouring columns have to differ, or there would be no trench between them
+        if heights.last() != Some(&height) {
+            heights.push(height);
+        }
+    }
+
+    let mut digs = vec![('R', widths.iter().sum()), ('U', heights[n_columns - 1])];
+    for i in (1..n_columns).rev() {
+        digs.push(('L', widths[i]));
+        let (from, to) = (heights[i], heights[i - 1]);
+        digs.push(if to > from {
+            ('U', to - from)
+        } else {
+            ('D', from - to)
+        });
+    }
+    digs.push(('L', widths[0]));
+    digs.push(('D', heights[0]));
+    digs
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(1);
+    let digs = columns(rng, size, 10);
+    // the trench along the bottom has to fit in the five hex digits too
+    let hidden = columns(rng, size, 0xf_ffff / size as u32);
+
+    let mut plan = String::new();
+    for ((direction, metres), (hidden_direction, hidden_metres)) in digs.into_iter().zip(hidden) {
+        let hidden_direction = match hidden_direction {
+            'R' => 0,
+            'D' => 1,
+            'L' => 2,
+            _ => 3,
+        };
+        let colour = hidden_metres << 4 | hidden_direction;
+        writeln!(plan, "{direction} {metres} (#{colour:06x})").unwrap();
+    }
+    plan
+}
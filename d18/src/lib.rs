@@ -0,0 +1,87 @@
+use anyhow::{ensure, Context};
+use common::Solution;
+use grid::Direction;
+
+pub mod gen;
+pub mod p1;
+pub mod p2;
+mod parse;
+
+/// Digging `metres` of trench in `direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Dig {
+    direction: Direction,
+    metres: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Instruction {
+    dig: Dig,
+    /// The `RRGGBB` hex code of the colour to paint the trench, as a number
+    colour: u32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct DigPlan(Vec<Instruction>);
+
+/// How many cubic metres of lava the lagoon dug out by `digs` holds, counting the trench itself
+///
+/// Only the corners of the trench are visited: the shoelace formula gives the area enclosed by it,
+/// and Pick's theorem turns that into the number of cubes inside it
+fn lagoon_size(digs: impl IntoIterator<Item = Dig>) -> anyhow::Result<u64> {
+    let (mut x, mut y) = (0i64, 0i64);
+    let mut twice_area = 0i128;
+    let mut trench = 0i128;
+    for Dig { direction, metres } in digs {
+        let metres = i64::from(metres);
+        let (next_x, next_y) = match direction {
+            Direction::Up => (x, y - metres),
+            Direction::Right => (x + metres, y),
+            Direction::Down => (x, y + metres),
+            Direction::Left => (x - metres, y),
+        };
+        twice_area += i128::from(x) * i128::from(next_y) - i128::from(next_x) * i128::from(y);
+        trench += i128::from(metres);
+        (x, y) = (next_x, next_y);
+    }
+    ensure!(
+        (x, y) == (0, 0),
+        "the trench doesn't lead back to where the digging started"
+    );
+
+    // Pick's theorem: A = i + b/2 - 1, with the trench making up the b cubes on the boundary,
+    // so the i + b cubes of the lagoon are A + b/2 + 1 (a closed trench is always of even length)
+    let size = twice_area.abs() / 2 + trench / 2 + 1;
+    u64::try_from(size).context("the lagoon is too large")
+}
+
+const DAY: u8 = 18;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn digs(plan: &[(Direction, u32)]) -> Vec<Dig> {
+        plan.iter()
+            .map(|&(direction, metres)| Dig { direction, metres })
+            .collect()
+    }
+
+    #[test_case(&[(Direction::Right, 2), (Direction::Down, 2), (Direction::Left, 2), (Direction::Up, 2)] => 9; "square")]
+    #[test_case(&[(Direction::Up, 2), (Direction::Left, 2), (Direction::Down, 2), (Direction::Right, 2)] => 9; "counterclockwise")]
+    #[test_case(&[(Direction::Right, 3), (Direction::Left, 3)] => 4; "there and back")]
+    fn size(plan: &[(Direction, u32)]) -> u64 {
+        lagoon_size(digs(plan)).unwrap()
+    }
+
+    #[test]
+    fn open_trench() {
+        let plan = digs(&[(Direction::Right, 2), (Direction::Down, 2)]);
+        assert!(lagoon_size(plan).is_err());
+    }
+}
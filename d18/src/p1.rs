@@ -0,0 +1,22 @@
+use core::str::FromStr;
+
+use common::Part;
+
+use crate::{lagoon_size, DigPlan};
+
+pub fn p1(file: &str) -> anyhow::Result<u64> {
+    solve(&DigPlan::from_str(file)?)
+}
+
+fn solve(plan: &DigPlan) -> anyhow::Result<u64> {
+    lagoon_size(plan.0.iter().map(|instruction| instruction.dig))
+}
+
+pub struct P1;
+
+common::solution!(
+    P1,
+    Part::One,
+    |file| DigPlan::from_str(file)?,
+    |plan| solve(&plan)
+);
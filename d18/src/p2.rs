@@ -0,0 +1,73 @@
+use core::str::FromStr;
+
+use anyhow::bail;
+use common::Part;
+use grid::Direction;
+
+use crate::{lagoon_size, Dig, DigPlan, Instruction};
+
+impl Instruction {
+    /// The dig hidden in the colour: the first five hex digits are the metres,
+    /// and the last one the direction
+    fn decode(&self) -> anyhow::Result<Dig> {
+        let direction = match self.colour & 0xf {
+            0 => Direction::Right,
+            1 => Direction::Down,
+            2 => Direction::Left,
+            3 => Direction::Up,
+            d => bail!("invalid direction in colour #{:06x}: {d}", self.colour),
+        };
+        Ok(Dig {
+            direction,
+            metres: self.colour >> 4,
+        })
+    }
+}
+
+fn decode(plan: &DigPlan) -> anyhow::Result<Vec<Dig>> {
+    plan.0.iter().map(Instruction::decode).collect()
+}
+
+pub fn p2(file: &str) -> anyhow::Result<u64> {
+    lagoon_size(decode(&DigPlan::from_str(file)?)?)
+}
+
+pub struct P2;
+
+common::solution!(
+    P2,
+    Part::Two,
+    |file| decode(&DigPlan::from_str(file)?)?,
+    lagoon_size
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0x70_c7_10 => Dig { direction: Direction::Right, metres: 461_937 })]
+    #[test_case(0x0d_c5_71 => Dig { direction: Direction::Down, metres: 56_407 })]
+    #[test_case(0x7a_21_e3 => Dig { direction: Direction::Up, metres: 500_254 })]
+    fn decode(colour: u32) -> Dig {
+        let dig = Dig {
+            direction: Direction::Up,
+            metres: 0,
+        };
+        Instruction { dig, colour }.decode().unwrap()
+    }
+
+    #[test]
+    fn invalid_direction() {
+        let dig = Dig {
+            direction: Direction::Up,
+            metres: 0,
+        };
+        assert!(Instruction {
+            dig,
+            colour: 0x00_00_14
+        }
+        .decode()
+        .is_err());
+    }
+}
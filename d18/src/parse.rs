@@ -0,0 +1,92 @@
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
+use grid::Direction;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while_m_n},
+    character::complete::{char, newline, u32},
+    combinator::{map, map_res, value},
+    sequence::{delimited, separated_pair},
+};
+
+use crate::{Dig, DigPlan, Instruction};
+
+fn direction(i: &str) -> IResult<&str, Direction> {
+    rule(
+        "direction",
+        alt((
+            value(Direction::Up, char('U')),
+            value(Direction::Right, char('R')),
+            value(Direction::Down, char('D')),
+            value(Direction::Left, char('L')),
+        )),
+    )(i)
+}
+
+fn dig(i: &str) -> IResult<&str, Dig> {
+    rule(
+        "dig",
+        map(
+            separated_pair(direction, char(' '), u32),
+            |(direction, metres)| Dig { direction, metres },
+        ),
+    )(i)
+}
+
+fn colour(i: &str) -> IResult<&str, u32> {
+    rule(
+        "colour",
+        delimited(
+            tag("(#"),
+            map_res(
+                take_while_m_n(6, 6, |c: char| c.is_ascii_hexdigit()),
+                |hex| u32::from_str_radix(hex, 16),
+            ),
+            char(')'),
+        ),
+    )(i)
+}
+
+fn instruction(i: &str) -> IResult<&str, Instruction> {
+    rule(
+        "instruction",
+        map(separated_pair(dig, char(' '), colour), |(dig, colour)| {
+            Instruction { dig, colour }
+        }),
+    )(i)
+}
+
+fn dig_plan(i: &str) -> IResult<&str, DigPlan> {
+    rule(
+        "dig_plan",
+        map(separated_list1(newline, instruction), DigPlan),
+    )(i)
+}
+
+impl_from_str_from_nom_parser!(dig_plan, DigPlan);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn instruction() {
+        let plan: DigPlan = "R 6 (#70c710)".parse().unwrap();
+        let Instruction { dig, colour } = plan.0[0];
+        assert_eq!(
+            dig,
+            Dig {
+                direction: Direction::Right,
+                metres: 6
+            }
+        );
+        assert_eq!(colour, 0x70_c7_10);
+    }
+
+    #[test]
+    fn short_colour() {
+        assert!("R 6 (#70c71)".parse::<DigPlan>().is_err());
+    }
+}
@@ -0,0 +1 @@
+common::generated_input_tests!(d18::gen::input, d18::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,67 @@
+//! Systems of `size` workflows rooted at `in`, followed by `size` parts
+//!
+//! The workflows only ever send parts to workflows defined after them, so they can't loop
+
+use std::collections::HashSet;
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+fn name(rng: &mut StdRng) -> String {
+    (0..rng.gen_range(2..=3))
+        .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+        .collect()
+}
+
+/// A destination out of the accepting/rejecting ones and the `later` workflows
+fn destination<'a>(rng: &mut StdRng, later: &'a [String]) -> &'a str {
+    if later.is_empty() || rng.gen_bool(0.3) {
+        if rng.gen_bool(0.5) {
+            "A"
+        } else {
+            "R"
+        }
+    } else {
+        later.choose(rng).unwrap()
+    }
+}
+
+fn workflow(rng: &mut StdRng, name: &str, later: &[String]) -> String {
+    let rules: Vec<String> = (0..rng.gen_range(1..=4))
+        .map(|_| {
+            let category = *b"xmas".choose(rng).unwrap() as char;
+            let cmp = if rng.gen_bool(0.5) { '<' } else { '>' };
+            let value = rng.gen_range(1..=4000);
+            format!("{category}{cmp}{value}:{}", destination(rng, later))
+        })
+        .collect();
+    format!(
+        "{name}{{{},{}}}\n",
+        rules.join(","),
+        destination(rng, later)
+    )
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut names = vec!["in".to_string()];
+    let mut seen: HashSet<String> = names.iter().cloned().collect();
+    while names.len() < size.max(1) {
+        let name = name(rng);
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut workflows: Vec<String> = (0..names.len())
+        .map(|i| workflow(rng, &names[i], &names[i + 1..]))
+        .collect();
+    workflows.shuffle(rng);
+
+    let parts: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|()| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}\n")
+        })
+        .collect();
+
+    workflows.concat() + "\n" + &parts.concat()
+}
@@ -1,11 +1,16 @@
 use core::ops::Index;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use anyhow::{bail, ensure};
+use common::Solution;
+
+pub mod gen;
 pub mod p1;
 pub mod p2;
 mod parse;
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Part {
     x: u32,
     m: u32,
@@ -28,6 +33,7 @@ impl Index<&Category> for Part {
 type WorkflowName<'a> = &'a str;
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Category {
     X,
     M,
@@ -36,23 +42,27 @@ enum Category {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Cmp {
     Less,
     Greater,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Destination<'a> {
     Accept,
     Reject,
-    Workflow(WorkflowName<'a>),
+    Workflow(#[cfg_attr(feature = "serde", serde(borrow))] WorkflowName<'a>),
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Rule<'a> {
     category: Category,
     cmp: Cmp,
     value: u32,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     dest: Destination<'a>,
 }
 
@@ -62,21 +72,41 @@ struct Workflow<'a> {
 }
 
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct WorkflowInner<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     rules: Vec<Rule<'a>>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
     last_rule: Destination<'a>,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Workflows<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
     inner: HashMap<&'a str, WorkflowInner<'a>>,
 }
 
 impl<'a> Workflows<'a> {
-    fn new(workflows: Vec<Workflow<'a>>) -> Self {
-        let mut workflows_map: HashMap<_, _> = workflows
-            .into_iter()
-            .map(|Workflow { name, inner }| (name, inner))
-            .collect();
+    fn new(workflows: Vec<Workflow<'a>>) -> anyhow::Result<Self> {
+        let mut workflows_map = HashMap::with_capacity(workflows.len());
+        for Workflow { name, inner } in workflows {
+            ensure!(
+                workflows_map.insert(name, inner).is_none(),
+                "workflow `{name}` is defined more than once"
+            );
+        }
+
+        ensure!(
+            workflows_map.contains_key("in"),
+            "there is no `in` workflow to start from"
+        );
+
+        // every part must eventually be accepted or rejected,
+        // so the workflows must only send parts to existing workflows, and never in circles
+        let mut done = HashSet::with_capacity(workflows_map.len());
+        for &name in core::iter::once(&"in").chain(workflows_map.keys()) {
+            check_destinations(&workflows_map, name, &mut vec![], &mut done)?;
+        }
 
         // optimize: simplify rule chains of forms:
         // - `X:Y,?:A,?:A,...,A` - to `X:Y,A`
@@ -91,8 +121,67 @@ impl<'a> Workflows<'a> {
             w_inner.rules.truncate(w_inner.rules.len() - to_truncate);
         }
 
-        Self {
+        Ok(Self {
             inner: workflows_map,
+        })
+    }
+}
+
+/// Checks that the workflows reachable from `name` all exist and don't loop,
+/// given the path of workflows that led to `name`
+fn check_destinations<'a>(
+    workflows: &HashMap<&'a str, WorkflowInner<'a>>,
+    name: WorkflowName<'a>,
+    path: &mut Vec<WorkflowName<'a>>,
+    done: &mut HashSet<WorkflowName<'a>>,
+) -> anyhow::Result<()> {
+    if done.contains(name) {
+        return Ok(());
+    }
+    if path.contains(&name) {
+        bail!("workflows loop: {} -> {name}", path.join(" -> "));
+    }
+
+    let WorkflowInner { rules, last_rule } = &workflows[name];
+    path.push(name);
+    for dest in rules.iter().map(|rule| &rule.dest).chain([last_rule]) {
+        if let &Destination::Workflow(next) = dest {
+            ensure!(
+                workflows.contains_key(next),
+                "workflow `{name}` sends parts to unknown workflow `{next}`"
+            );
+            check_destinations(workflows, next, path, done)?;
         }
     }
+    path.pop();
+
+    done.insert(name);
+    Ok(())
+}
+
+const DAY: u8 = 19;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("in{x>10:A,R}\nin{m<5:R,A}" => "workflow `in` is defined more than once"; "duplicate")]
+    #[test_case("qs{x>10:A,R}" => "there is no `in` workflow to start from"; "no start")]
+    #[test_case("in{x>10:qs,R}" => "workflow `in` sends parts to unknown workflow `qs`"; "unknown in rule")]
+    #[test_case("in{x>10:A,qs}" => "workflow `in` sends parts to unknown workflow `qs`"; "unknown in last rule")]
+    #[test_case("in{x>10:A,qs}\nqs{m<5:in,R}" => "workflows loop: in -> qs -> in"; "cycle")]
+    #[test_case("in{x>10:A,in}" => "workflows loop: in -> in"; "self cycle")]
+    fn invalid(workflows: &str) -> String {
+        let workflows = workflows
+            .lines()
+            .map(|w| Workflow::try_from(w).unwrap())
+            .collect();
+        let Err(e) = Workflows::new(workflows) else {
+            panic!("accepted invalid workflows");
+        };
+        e.to_string()
+    }
 }
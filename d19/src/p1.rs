@@ -2,6 +2,8 @@ use core::str::FromStr;
 
 use anyhow::Context;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::{Cmp, Destination, Part, Rule, Workflow, WorkflowInner, WorkflowName, Workflows};
 
@@ -30,10 +32,11 @@ impl Rule<'_> {
 }
 
 impl Workflows<'_> {
-    fn consider(&self, part: &Part) -> Destination {
+    pub(crate) fn consider(&self, part: &Part) -> Destination<'_> {
         self.consider_inner(part, "in")
     }
 
+    #[tracing::instrument(level = "trace", skip(self), ret)]
     fn consider_inner(&self, part: &Part, workflow_name: WorkflowName) -> Destination {
         let WorkflowInner {
             rules,
@@ -65,32 +68,38 @@ impl Workflows<'_> {
 }
 
 pub fn p1(file: &str) -> anyhow::Result<u32> {
+    Ok(solve(parse(file)?))
+}
+
+fn parse(file: &str) -> anyhow::Result<(Workflows<'_>, Vec<Part>)> {
     let (workflows, parts) = file
         .split_once("\n\n")
         .context("no empty line between workflows and parts")?;
 
-    let workflows = workflows.lines().map(Workflow::try_from).try_collect()?;
-    let workflows = Workflows::new(workflows);
+    // the parts come after the workflows and an empty line
+    let parts_start = workflows.lines().count() + 1;
 
-    let parts: Vec<_> = parts.lines().map(Part::from_str).try_collect()?;
+    let workflows = common::parse::lines(workflows, Workflow::try_from).try_collect()?;
+    let workflows = Workflows::new(workflows)?;
 
-    let res = parts
-        .into_iter()
+    let parts = common::parse::lines(parts, Part::from_str)
+        .map(|part| part.map_err(|e| e.shifted(parts_start)))
+        .try_collect()?;
+
+    Ok((workflows, parts))
+}
+
+fn solve((workflows, parts): (Workflows, Vec<Part>)) -> u32 {
+    #[cfg(feature = "parallel")]
+    let parts = parts.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let parts = parts.into_iter();
+    parts
         .filter(|p| matches!(workflows.consider(p), Destination::Accept))
         .map(|Part { x, m, a, s }| x + m + a + s)
-        .sum();
-    Ok(res)
+        .sum()
 }
 
-#[cfg(test)]
-mod test {
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
+pub struct P1;
 
-    #[test_case(EXAMPLE => 19114)]
-    #[test_case(REAL => 319_295)]
-    fn p1(inp: &str) -> u32 {
-        super::p1(inp).unwrap()
-    }
-}
+common::solution!(P1, common::Part::One, |file| parse(file)?, solve);
@@ -53,12 +53,14 @@ impl Rule<'_> {
 
         let (changed_range, unchanged_range) = match cmp {
             Cmp::Less => {
-                let max_changed = cmp::min(end, *value - 1);
-                (start..=max_changed, *value..=end)
+                let max_changed = cmp::min(end, value.saturating_sub(1));
+                let min_unchanged = cmp::max(start, *value);
+                (start..=max_changed, min_unchanged..=end)
             }
             Cmp::Greater => {
-                let min_changed = cmp::max(start, *value + 1);
-                (min_changed..=end, start..=*value)
+                let min_changed = cmp::max(start, value.saturating_add(1));
+                let max_unchanged = cmp::min(end, *value);
+                (min_changed..=end, start..=max_unchanged)
             }
         };
 
@@ -77,6 +79,7 @@ impl Workflows<'_> {
         self.consider_range_inner(part_range, "in")
     }
 
+    #[tracing::instrument(level = "trace", skip(self), ret)]
     fn consider_range_inner(
         &self,
         mut part_range: PartRange,
@@ -104,9 +107,9 @@ impl Workflows<'_> {
         }
 
         match *last {
-            Destination::Workflow(_) => {
-                // res.extend(self.consider_range_inner(part_range, wf_name));
-                unreachable!()
+            Destination::Workflow(wf_name) => {
+                // send to another workflow
+                res.extend(self.consider_range_inner(part_range, wf_name));
             }
             dest => {
                 res.push((part_range, dest));
@@ -117,13 +120,19 @@ impl Workflows<'_> {
 }
 
 pub fn p2(file: &str) -> anyhow::Result<usize> {
+    Ok(solve(&parse(file)?))
+}
+
+fn parse(file: &str) -> anyhow::Result<Workflows<'_>> {
     let (workflows, _) = file
         .split_once("\n\n")
         .context("no empty line between workflows and parts")?;
 
-    let workflows = workflows.lines().map(Workflow::try_from).try_collect()?;
-    let workflows = Workflows::new(workflows);
+    let workflows = common::parse::lines(workflows, Workflow::try_from).try_collect()?;
+    Workflows::new(workflows)
+}
 
+fn solve(workflows: &Workflows) -> usize {
     let part_range = PartRange {
         x: 1..=4000,
         m: 1..=4000,
@@ -133,27 +142,107 @@ pub fn p2(file: &str) -> anyhow::Result<usize> {
 
     let destinations = workflows.consider_range(part_range);
 
-    let res: usize = destinations
+    destinations
         .into_iter()
         .filter(|(_, dest)| matches!(dest, Destination::Accept))
         .map(|(pr, _)| {
             let PartRange { x, m, a, s } = pr;
             x.count() * m.count() * a.count() * s.count()
         })
-        .sum();
-
-    Ok(res)
+        .sum()
 }
 
+pub struct P2;
+
+common::solution!(P2, common::Part::Two, |file| parse(file)?, |workflows| {
+    solve(&workflows)
+});
+
 #[cfg(test)]
 mod test {
-    use test_case::test_case;
-    const EXAMPLE: &str = include_str!("../inputs/example.txt");
-    const REAL: &str = include_str!("../inputs/real.txt");
-
-    #[test_case(EXAMPLE => 167_409_079_868_000)]
-    #[test_case(REAL => 110_807_725_108_076)]
-    fn p2(inp: &str) -> usize {
-        super::p2(inp).unwrap()
+    use core::fmt::Write;
+
+    use super::*;
+    use crate::Part;
+    use proptest::prelude::*;
+
+    /// Parts can only have values up to this one, so that all of them can be tried out
+    const MAX_VALUE: u32 = 5;
+
+    const NAMES: [&str; 4] = ["in", "b", "c", "d"];
+
+    /// The rules of a workflow, as `(category, is_less, value, destination)`, and its last rule
+    ///
+    /// Destinations are indices into the accepting/rejecting ones followed by the workflows defined
+    /// after this one, so that the workflows can't loop
+    type RawWorkflow = (Vec<(usize, bool, u32, usize)>, usize);
+
+    fn raw_workflows() -> impl Strategy<Value = Vec<RawWorkflow>> {
+        let rule = (0..4usize, any::<bool>(), 0..=MAX_VALUE + 1, any::<usize>());
+        let workflow = (prop::collection::vec(rule, 1..4), any::<usize>());
+        prop::collection::vec(workflow, 1..=NAMES.len())
+    }
+
+    fn to_text(raw: &[RawWorkflow]) -> String {
+        let mut text = String::new();
+        for (i, (rules, last_rule)) in raw.iter().enumerate() {
+            let destinations: Vec<&str> = ["A", "R"]
+                .into_iter()
+                .chain(NAMES[i + 1..raw.len()].iter().copied())
+                .collect();
+            let destination = |d: usize| destinations[d % destinations.len()];
+
+            text.push_str(NAMES[i]);
+            text.push('{');
+            for &(category, is_less, value, dest) in rules {
+                let category = ['x', 'm', 'a', 's'][category];
+                let cmp = if is_less { '<' } else { '>' };
+                write!(text, "{category}{cmp}{value}:{},", destination(dest)).unwrap();
+            }
+            text.push_str(destination(*last_rule));
+            text.push_str("}\n");
+        }
+        text
+    }
+
+    proptest! {
+        #[test]
+        fn ranges_agree_with_considering_every_part(raw in raw_workflows()) {
+            let text = to_text(&raw);
+            let workflows = Workflows::new(
+                text.lines().map(|w| Workflow::try_from(w).unwrap()).collect(),
+            )
+            .unwrap();
+
+            let part_range = PartRange {
+                x: 1..=MAX_VALUE,
+                m: 1..=MAX_VALUE,
+                a: 1..=MAX_VALUE,
+                s: 1..=MAX_VALUE,
+            };
+            let destinations = workflows.consider_range(part_range);
+
+            let values = || 1..=MAX_VALUE;
+            for (x, m, a, s) in itertools::iproduct!(values(), values(), values(), values()) {
+                let part = Part { x, m, a, s };
+                let containing: Vec<Destination> = destinations
+                    .iter()
+                    .filter(|(pr, _)| {
+                        pr.x.contains(&x) && pr.m.contains(&m) && pr.a.contains(&a) && pr.s.contains(&s)
+                    })
+                    .map(|&(_, dest)| dest)
+                    .collect();
+                prop_assert_eq!(containing, vec![workflows.consider(&part)], "part {:?}", (x, m, a, s));
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trips_through_json() {
+        let workflows = parse(include_str!("../inputs/example.txt")).unwrap();
+        let json = serde_json::to_string(&workflows).unwrap();
+        let deserialized: Workflows = serde_json::from_str(&json).unwrap();
+        assert_eq!(solve(&deserialized), solve(&workflows));
     }
 }
@@ -0,0 +1 @@
+common::generated_input_tests!(d19::gen::input, d19::SOLUTIONS, [1, 5, 20]);
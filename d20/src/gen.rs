@@ -0,0 +1,58 @@
+//! Networks of up to 4 counters, made like those of the puzzle, each counting up to a number
+//! of up to `size` bits before sending a high pulse to the conjunction feeding `rx`
+//!
+//! Each counter is a chain of flip-flops, one for each bit, with a conjunction seeing the bits
+//! which are on in the number it counts up to. When they all are, the conjunction sends a low pulse
+//! to the bits which are off, and to the lowest one, which adds up to overflowing the counter back to 0
+
+use core::fmt::Write;
+use std::collections::HashSet;
+
+use common::gen::{Rng, StdRng};
+
+/// A name of two letters which isn't in `taken` yet
+fn name(rng: &mut StdRng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let name: String = (0..2)
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')))
+            .collect();
+        if name != "rx" && taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let n_counters = size.clamp(1, 4);
+    let max_bits = size.clamp(1, 12);
+
+    let mut taken = HashSet::new();
+    let hub = name(rng, &mut taken);
+    let mut firsts = vec![];
+    let mut modules = String::new();
+    for _ in 0..n_counters {
+        let n_bits = rng.gen_range(1..=max_bits);
+        let bits: Vec<String> = (0..n_bits).map(|_| name(rng, &mut taken)).collect();
+        let (counter, inverter) = (name(rng, &mut taken), name(rng, &mut taken));
+        // the highest bit is always on, so that the number takes all of them,
+        // and so is the lowest one, which gets the low pulse from the conjunction as it's on
+        let number: u32 = rng.gen_range(1 << (n_bits - 1)..1 << n_bits) | 1;
+
+        let mut reset = vec![inverter.as_str(), bits[0].as_str()];
+        for (i, bit) in bits.iter().enumerate() {
+            let mut outputs: Vec<&str> = bits.get(i + 1).map(String::as_str).into_iter().collect();
+            if number & 1 << i == 0 {
+                reset.push(bit);
+            } else {
+                outputs.push(&counter);
+            }
+            writeln!(modules, "%{bit} -> {}", outputs.join(", ")).unwrap();
+        }
+        writeln!(modules, "&{counter} -> {}", reset.join(", ")).unwrap();
+        writeln!(modules, "&{inverter} -> {hub}").unwrap();
+        firsts.push(bits[0].clone());
+    }
+    writeln!(modules, "&{hub} -> rx").unwrap();
+
+    format!("broadcaster -> {}\n{modules}", firsts.join(", "))
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use anyhow::ensure;
+use common::Solution;
+
+pub mod gen;
+pub(crate) mod machine;
+pub mod p1;
+pub mod p2;
+mod parse;
+
+type ModuleName<'a> = &'a str;
+
+/// The module the button sends its pulse to
+const BROADCASTER: ModuleName = "broadcaster";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Kind {
+    Broadcaster,
+    /// `%`
+    FlipFlop,
+    /// `&`
+    Conjunction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Module<'a> {
+    kind: Kind,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    outputs: Vec<ModuleName<'a>>,
+}
+
+/// The modules, by name
+///
+/// The modules which are only ever sent pulses to, like `output` or `rx`, aren't in here
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Network<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    modules: HashMap<ModuleName<'a>, Module<'a>>,
+}
+
+impl<'a> Network<'a> {
+    fn new(modules: Vec<(ModuleName<'a>, Module<'a>)>) -> anyhow::Result<Self> {
+        let mut modules_map = HashMap::with_capacity(modules.len());
+        for (name, module) in modules {
+            ensure!(
+                (name == BROADCASTER) == (module.kind == Kind::Broadcaster),
+                "only `{BROADCASTER}` can be the broadcaster, and it can't be anything else"
+            );
+            ensure!(
+                modules_map.insert(name, module).is_none(),
+                "module `{name}` is defined more than once"
+            );
+        }
+        ensure!(
+            modules_map.contains_key(BROADCASTER),
+            "there is no `{BROADCASTER}` for the button to send pulses to"
+        );
+        Ok(Self {
+            modules: modules_map,
+        })
+    }
+}
+
+const DAY: u8 = 20;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("broadcaster -> a\n%a -> b\n&a -> b" => "module `a` is defined more than once"; "duplicate")]
+    #[test_case("%a -> b" => "there is no `broadcaster` for the button to send pulses to"; "no broadcaster")]
+    #[test_case("%broadcaster -> a" => "only `broadcaster` can be the broadcaster, and it can't be anything else"; "flip-flop broadcaster")]
+    fn invalid(network: &str) -> String {
+        let Err(e) = Network::try_from(network) else {
+            panic!("accepted an invalid network");
+        };
+        format!("{e:#}")
+    }
+}
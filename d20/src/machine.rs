@@ -0,0 +1,159 @@
+//! The network of modules, running: the modules are numbered, and the pulses go through a queue
+
+use std::collections::VecDeque;
+
+use crate::{Kind, Network, BROADCASTER};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Pulse {
+    pub(crate) from: usize,
+    pub(crate) to: usize,
+    pub(crate) high: bool,
+}
+
+enum State {
+    Button,
+    Broadcaster,
+    FlipFlop {
+        on: bool,
+    },
+    Conjunction {
+        /// Whether the last pulse from each of the inputs was high
+        memory: Vec<bool>,
+        n_high: usize,
+    },
+    /// A module which only receives pulses
+    Untyped,
+}
+
+pub(crate) struct Machine<'a> {
+    names: Vec<&'a str>,
+    states: Vec<State>,
+    /// Where each of the modules sends its pulses: to which module, and as which of its inputs
+    outputs: Vec<Vec<(usize, usize)>>,
+    inputs: Vec<Vec<usize>>,
+    button: usize,
+    queue: VecDeque<(Pulse, usize)>,
+}
+
+impl<'a> Machine<'a> {
+    pub(crate) fn new(network: &Network<'a>) -> Self {
+        // the modules which are only ever sent pulses to get numbers too
+        let mut names: Vec<&str> = network
+            .modules
+            .iter()
+            .flat_map(|(&name, module)| {
+                core::iter::once(name).chain(module.outputs.iter().copied())
+            })
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        let index = |name: &str| names.binary_search(&name).expect("all the names are in");
+
+        let n = names.len() + 1;
+        let button = names.len();
+        let mut outputs = vec![vec![]; n];
+        let mut inputs: Vec<Vec<usize>> = vec![vec![]; n];
+        let mut connect = |from: usize, to: usize| {
+            outputs[from].push((to, inputs[to].len()));
+            inputs[to].push(from);
+        };
+        connect(button, index(BROADCASTER));
+        for (i, name) in names.iter().enumerate() {
+            if let Some(module) = network.modules.get(name) {
+                for output in &module.outputs {
+                    connect(i, index(output));
+                }
+            }
+        }
+
+        let mut states: Vec<_> = names
+            .iter()
+            .enumerate()
+            .map(
+                |(i, name)| match network.modules.get(name).map(|m| m.kind) {
+                    Some(Kind::Broadcaster) => State::Broadcaster,
+                    Some(Kind::FlipFlop) => State::FlipFlop { on: false },
+                    Some(Kind::Conjunction) => State::Conjunction {
+                        memory: vec![false; inputs[i].len()],
+                        n_high: 0,
+                    },
+                    None => State::Untyped,
+                },
+            )
+            .collect();
+        states.push(State::Button);
+        names.push("button");
+
+        Self {
+            names,
+            states,
+            outputs,
+            inputs,
+            button,
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn index(&self, name: &str) -> Option<usize> {
+        self.names[..self.button].binary_search(&name).ok()
+    }
+
+    pub(crate) fn name(&self, i: usize) -> &'a str {
+        self.names[i]
+    }
+
+    pub(crate) fn inputs(&self, i: usize) -> &[usize] {
+        &self.inputs[i]
+    }
+
+    pub(crate) fn is_conjunction(&self, i: usize) -> bool {
+        matches!(self.states[i], State::Conjunction { .. })
+    }
+
+    /// Pushes the button, and lets the pulses go through the network until there are none left,
+    /// calling `on_pulse` on each of them in the order they're sent, the button's included
+    pub(crate) fn push_button(&mut self, mut on_pulse: impl FnMut(Pulse)) {
+        let (broadcaster, slot) = self.outputs[self.button][0];
+        let pulse = Pulse {
+            from: self.button,
+            to: broadcaster,
+            high: false,
+        };
+        self.queue.push_back((pulse, slot));
+
+        while let Some((pulse, slot)) = self.queue.pop_front() {
+            on_pulse(pulse);
+            let high = match &mut self.states[pulse.to] {
+                State::Broadcaster => pulse.high,
+                State::FlipFlop { on } => {
+                    if pulse.high {
+                        continue;
+                    }
+                    *on = !*on;
+                    *on
+                }
+                State::Conjunction { memory, n_high } => {
+                    if memory[slot] != pulse.high {
+                        memory[slot] = pulse.high;
+                        if pulse.high {
+                            *n_high += 1;
+                        } else {
+                            *n_high -= 1;
+                        }
+                    }
+                    *n_high < memory.len()
+                }
+                State::Button | State::Untyped => continue,
+            };
+            for &(to, slot) in &self.outputs[pulse.to] {
+                let next = Pulse {
+                    from: pulse.to,
+                    to,
+                    high,
+                };
+                self.queue.push_back((next, slot));
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use common::Part;
+
+use crate::{machine::Machine, Network};
+
+const N_PUSHES: usize = 1000;
+
+pub fn p1(file: &str) -> anyhow::Result<u64> {
+    Ok(solve(&Network::try_from(file)?))
+}
+
+/// The number of low pulses sent, times that of the high ones
+fn solve(network: &Network) -> u64 {
+    let mut machine = Machine::new(network);
+    let (mut low, mut high) = (0u64, 0u64);
+    for _ in 0..N_PUSHES {
+        machine.push_button(|pulse| {
+            if pulse.high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        });
+    }
+    low * high
+}
+
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| Network::try_from(file)?, |network| {
+    solve(&network)
+});
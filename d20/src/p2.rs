@@ -0,0 +1,161 @@
+use anyhow::{bail, ensure, Context};
+use common::{cycle::chinese_remainder, Part};
+use num::Integer;
+
+use crate::{machine::Machine, Network};
+
+/// How many times to push the button at most while waiting for the inputs of the conjunction
+/// feeding `rx` to send it a high pulse twice
+const MAX_PUSHES: usize = 1 << 16;
+
+/// When one of the inputs of the conjunction feeding `rx` sends it high pulses
+#[derive(Debug)]
+struct Period {
+    /// The push at which the first high pulse is sent
+    first: usize,
+    /// The number of pushes between the high pulses
+    len: usize,
+}
+
+pub fn p2(file: &str) -> anyhow::Result<u64> {
+    solve(&Network::try_from(file)?)
+}
+
+/// The number of pushes of the button it takes for `rx` to get a low pulse
+///
+/// That's when the conjunction feeding `rx` has got high pulses from all of its inputs, which,
+/// in the networks of the puzzle, each send one every so many pushes (and a low one right after),
+/// like the ghosts of day 8 going in circles
+fn solve(network: &Network) -> anyhow::Result<u64> {
+    let mut machine = Machine::new(network);
+    let rx = machine.index("rx").context("there is no `rx` module")?;
+    let &[hub] = machine.inputs(rx) else {
+        bail!("`rx` is fed by more than a single module");
+    };
+    ensure!(
+        machine.is_conjunction(hub),
+        "`{}`, which feeds `rx`, is not a conjunction",
+        machine.name(hub)
+    );
+    let feeders = machine.inputs(hub).to_vec();
+    ensure!(
+        !feeders.is_empty(),
+        "`{}`, which feeds `rx`, has no inputs",
+        machine.name(hub)
+    );
+
+    // the pushes at which each of the feeders has sent high pulses to the hub
+    let mut highs = vec![vec![]; feeders.len()];
+    for push in 1..=MAX_PUSHES {
+        if highs.iter().all(|h: &Vec<_>| h.len() >= 2) {
+            break;
+        }
+        machine.push_button(|pulse| {
+            if pulse.to == hub && pulse.high {
+                let feeder = feeders.iter().position(|&f| f == pulse.from);
+                let highs = &mut highs[feeder.expect("only the feeders send to the hub")];
+                if highs.last() != Some(&push) {
+                    highs.push(push);
+                }
+            }
+        });
+    }
+
+    let mut periods = Vec::with_capacity(feeders.len());
+    for (&feeder, highs) in feeders.iter().zip(&highs) {
+        let &[first, second, ..] = highs.as_slice() else {
+            bail!(
+                "`{}` doesn't send high pulses to `{}` every so often, at least not within {MAX_PUSHES} pushes",
+                machine.name(feeder),
+                machine.name(hub)
+            );
+        };
+        let period = Period {
+            first,
+            len: second - first,
+        };
+        tracing::debug!(feeder = machine.name(feeder), ?period, "found the period");
+        periods.push(period);
+    }
+
+    // the pushes at which all of the feeders send a high pulse are the solutions
+    // of the system of congruences of their periods
+    let (r, m) = periods.iter().try_fold((0, 1), |c, period| {
+        let len = period.len as i128;
+        let (r, m) = chinese_remainder(c, (period.first as i128 % len, len))
+            .context("the feeders never send high pulses at the same push")?;
+        // keep clear of overflowing when combining the next one
+        ensure!(m <= i128::from(u64::MAX), "way too many pushes to count");
+        Ok((r, m))
+    })?;
+    let all_started = periods.iter().map(|p| p.first).max().unwrap_or(0) as i128;
+    let push = r + Integer::div_ceil(&(all_started - r).max(0), &m) * m;
+    Ok(u64::try_from(push)?)
+}
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Network::try_from(file)?, |network| {
+    solve(&network)
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    /// Counters of 3 and 5 pushes, the way the puzzle's networks count: the flip-flops `q`
+    /// and `p` are the bits of the counters, which reset them once `hq` and `hp` see them all on
+    const COUNTERS: &str = "\
+broadcaster -> qa, pa
+%qa -> qb, hq
+%qb -> hq
+&hq -> iq, qa
+&iq -> df
+%pa -> pb, hp
+%pb -> pc
+%pc -> hp
+&hp -> ip, pa, pb
+&ip -> df
+&df -> rx";
+
+    /// Pushes the button until `rx` gets a low pulse
+    fn brute_force(network: &Network) -> u64 {
+        let mut machine = Machine::new(network);
+        let rx = machine.index("rx").unwrap();
+        (1..=MAX_PUSHES as u64)
+            .find(|_| {
+                let mut low = false;
+                machine.push_button(|pulse| low |= pulse.to == rx && !pulse.high);
+                low
+            })
+            .expect("`rx` got a low pulse")
+    }
+
+    #[test]
+    fn counters() {
+        let network = Network::try_from(COUNTERS).unwrap();
+        assert_eq!(brute_force(&network), 15);
+        assert_eq!(solve(&network).unwrap(), 15);
+    }
+
+    #[test_case(1)]
+    #[test_case(2)]
+    #[test_case(3)]
+    fn generated_counters(size: usize) {
+        for seed in 0..8 {
+            let input = crate::gen::input(&mut common::gen::rng(seed), size);
+            let network = Network::try_from(input.as_str()).unwrap();
+            assert_eq!(solve(&network).unwrap(), brute_force(&network), "{input}");
+        }
+    }
+
+    #[test_case("broadcaster -> a\n%a -> b" => "there is no `rx` module"; "no rx")]
+    #[test_case("broadcaster -> a, b\n%a -> rx\n%b -> rx" => "`rx` is fed by more than a single module"; "two feeding rx")]
+    #[test_case("broadcaster -> a\n%a -> rx" => "`a`, which feeds `rx`, is not a conjunction"; "flip-flop feeding rx")]
+    #[test_case("broadcaster -> a\n%a -> a\n&b -> rx" => "`b`, which feeds `rx`, has no inputs"; "hub without inputs")]
+    #[test_case("broadcaster -> a\n%c -> b\n&b -> rx" => "`c` doesn't send high pulses to `b` every so often, at least not within 65536 pushes"; "no period")]
+    fn invalid(network: &str) -> String {
+        p2(network).unwrap_err().to_string()
+    }
+}
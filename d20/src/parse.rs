@@ -0,0 +1,73 @@
+use common::parse::{parse_all, rule, separated_list1, IResult};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, char, newline},
+    combinator::{map, opt},
+    sequence::{pair, separated_pair},
+};
+
+use crate::{Kind, Module, ModuleName, Network};
+
+fn module_name(i: &str) -> IResult<&str, ModuleName<'_>> {
+    rule("module_name", alpha1)(i)
+}
+
+fn kind(i: &str) -> IResult<&str, Option<char>> {
+    rule("kind", opt(alt((char('%'), char('&')))))(i)
+}
+
+fn module(i: &str) -> IResult<&str, (ModuleName<'_>, Module<'_>)> {
+    rule(
+        "module",
+        map(
+            separated_pair(
+                pair(kind, module_name),
+                tag(" -> "),
+                separated_list1(tag(", "), module_name),
+            ),
+            |((kind, name), outputs)| {
+                let kind = match kind {
+                    Some('%') => Kind::FlipFlop,
+                    Some(_) => Kind::Conjunction,
+                    // checked to be the broadcaster when putting the network together
+                    None => Kind::Broadcaster,
+                };
+                (name, Module { kind, outputs })
+            },
+        ),
+    )(i)
+}
+
+fn modules(i: &str) -> IResult<&str, Vec<(ModuleName<'_>, Module<'_>)>> {
+    rule("modules", separated_list1(newline, module))(i)
+}
+
+impl<'a> TryFrom<&'a str> for Network<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::new(parse_all(modules, s)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BROADCASTER;
+
+    #[test]
+    fn modules() {
+        let network = Network::try_from("broadcaster -> a, b\n%a -> b\n&b -> output").unwrap();
+        assert_eq!(
+            network.modules[BROADCASTER],
+            Module {
+                kind: Kind::Broadcaster,
+                outputs: vec!["a", "b"]
+            }
+        );
+        assert_eq!(network.modules["a"].kind, Kind::FlipFlop);
+        assert_eq!(network.modules["b"].kind, Kind::Conjunction);
+        assert!(!network.modules.contains_key("output"));
+    }
+}
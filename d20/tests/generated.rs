@@ -0,0 +1 @@
+common::generated_input_tests!(d20::gen::input, d20::SOLUTIONS, [1, 5, 20]);
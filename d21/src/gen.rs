@@ -0,0 +1,27 @@
+//! Gardens like those of the puzzle, `2 * size + 5` plots across
+//!
+//! The start is in the middle, and its row and column are clear of rocks, like the edges of the map,
+//! so that the walk spreads over the copies of the map evenly
+
+use common::gen::{Rng, StdRng};
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let width = 2 * size + 5;
+    let clear = [0, width / 2, width - 1];
+    (0..width)
+        .map(|y| {
+            let row: String = (0..width)
+                .map(|x| {
+                    if (x, y) == (width / 2, width / 2) {
+                        'S'
+                    } else if !clear.contains(&x) && !clear.contains(&y) && rng.gen_bool(0.15) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,133 @@
+use core::str::FromStr;
+use std::collections::VecDeque;
+
+use anyhow::bail;
+use common::Solution;
+use grid::Grid;
+
+pub mod gen;
+pub mod p1;
+pub mod p2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Tile {
+    Plot,
+    Rock,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Garden {
+    map: Grid<Tile>,
+    /// `(x, y)`
+    start: (usize, usize),
+}
+
+impl FromStr for Garden {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = Grid::parse(s, |c| match c {
+            '.' | 'S' => Ok(Tile::Plot),
+            '#' => Ok(Tile::Rock),
+            c => bail!("invalid tile: {c}"),
+        })?;
+
+        let mut starts = s.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == 'S')
+                .map(move |(x, _)| (x, y))
+        });
+        let Some(start) = starts.next() else {
+            bail!("no starting position `S` found");
+        };
+        if let Some(other) = starts.next() {
+            bail!("more than one starting position `S`: {start:?} and {other:?}");
+        }
+
+        Ok(Self { map, start })
+    }
+}
+
+impl Garden {
+    /// For each of `steps`, the number of plots which can be reached in exactly that many steps,
+    /// on the map repeated infinitely in all directions if it's `tiled`
+    ///
+    /// A plot reached in `d` steps can also be reached in `d + 2`, `d + 4`, ... steps,
+    /// by stepping back and forth, so it's those which are at most `steps` away
+    /// and whose distance is of the same parity
+    fn n_reachable(&self, steps: &[usize], tiled: bool) -> Vec<u64> {
+        let radius = steps.iter().copied().max().unwrap_or(0);
+
+        // the window of the plots within `radius` steps of the start
+        let side = 2 * radius + 1;
+        let (width, height) = (self.map.width() as i64, self.map.height() as i64);
+        let is_plot = |wx: usize, wy: usize| {
+            let x = self.start.0 as i64 + wx as i64 - radius as i64;
+            let y = self.start.1 as i64 + wy as i64 - radius as i64;
+            let inside = (0..width).contains(&x) && (0..height).contains(&y);
+            if !tiled && !inside {
+                return false;
+            }
+            let (x, y) = (x.rem_euclid(width) as usize, y.rem_euclid(height) as usize);
+            self.map.row(y)[x] == Tile::Plot
+        };
+
+        let mut seen = vec![false; side * side];
+        // how many plots are how many steps away
+        let mut n_at = vec![0u64; radius + 1];
+        let mut queue = VecDeque::from([(radius, radius, 0)]);
+        seen[radius * side + radius] = true;
+        while let Some((wx, wy, d)) = queue.pop_front() {
+            n_at[d] += 1;
+            if d == radius {
+                continue;
+            }
+            let neighbours = [
+                (wx.wrapping_sub(1), wy),
+                (wx + 1, wy),
+                (wx, wy.wrapping_sub(1)),
+                (wx, wy + 1),
+            ];
+            for (nx, ny) in neighbours {
+                if nx < side && ny < side && !seen[ny * side + nx] && is_plot(nx, ny) {
+                    seen[ny * side + nx] = true;
+                    queue.push_back((nx, ny, d + 1));
+                }
+            }
+        }
+
+        steps
+            .iter()
+            .map(|&s| n_at[..=s].iter().skip(s % 2).step_by(2).sum())
+            .collect()
+    }
+}
+
+const DAY: u8 = 21;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    const EXAMPLE: &str = include_str!("../inputs/example.txt");
+
+    #[test_case(6 => 16)]
+    #[test_case(10 => 50)]
+    #[test_case(50 => 1594)]
+    #[test_case(100 => 6536)]
+    fn tiled(steps: usize) -> u64 {
+        let garden = Garden::from_str(EXAMPLE).unwrap();
+        garden.n_reachable(&[steps], true)[0]
+    }
+
+    #[test]
+    fn start() {
+        assert!(Garden::from_str("..\n..").is_err());
+        assert!(Garden::from_str("S.\n.S").is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use core::str::FromStr;
+
+use common::Part;
+
+use crate::Garden;
+
+const STEPS: usize = 64;
+
+pub fn p1(file: &str) -> anyhow::Result<u64> {
+    Ok(solve(&Garden::from_str(file)?, STEPS))
+}
+
+fn solve(garden: &Garden, steps: usize) -> u64 {
+    garden.n_reachable(&[steps], false)[0]
+}
+
+pub struct P1;
+
+common::solution!(P1, Part::One, |file| Garden::from_str(file)?, |garden| {
+    solve(&garden, STEPS)
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let garden = Garden::from_str(include_str!("../inputs/example.txt")).unwrap();
+        assert_eq!(solve(&garden, 6), 16);
+    }
+}
@@ -0,0 +1,75 @@
+use core::str::FromStr;
+
+use anyhow::ensure;
+use common::{differences, Part};
+
+use crate::Garden;
+
+const STEPS: usize = 26_501_365;
+
+/// How many times across the map to walk before extrapolating
+const N_SAMPLES: usize = 8;
+
+/// How many of the last samples to extrapolate from, which is more than a quadratic needs,
+/// to make sure it is one
+const N_EXTRAPOLATED: usize = 5;
+
+pub fn p2(file: &str) -> anyhow::Result<u64> {
+    solve(&Garden::from_str(file)?, STEPS)
+}
+
+/// The number of plots which can be reached in exactly `steps` steps on the infinitely tiled map
+///
+/// Once the walk has gone a few times across the map, each further time across adds
+/// a ring of copies of the map, which grows by the same number of them every time.
+/// So, sampled at the step counts a whole number of maps apart, the number of plots reached
+/// becomes quadratic, and can be extrapolated from the last few of those samples by their differences
+fn solve(garden: &Garden, steps: usize) -> anyhow::Result<u64> {
+    ensure!(
+        garden.map.width() == garden.map.height(),
+        "the map isn't square"
+    );
+    let side = garden.map.width();
+
+    let (n_across, rest) = (steps / side, steps % side);
+    if n_across <= N_SAMPLES {
+        return Ok(garden.n_reachable(&[steps], true)[0]);
+    }
+
+    let samples: Vec<usize> = (N_SAMPLES - N_EXTRAPOLATED + 1..=N_SAMPLES)
+        .map(|k| rest + k * side)
+        .collect();
+    let n_reachable: Vec<i64> = garden
+        .n_reachable(&samples, true)
+        .into_iter()
+        .map(i64::try_from)
+        .collect::<Result<_, _>>()?;
+    ensure!(
+        differences::differences(n_reachable.clone()).len() <= 3,
+        "the number of plots reached doesn't grow quadratically, \
+        at least not after walking across the map {N_SAMPLES} times"
+    );
+    let n = differences::extrapolate_by(n_reachable, n_across - N_SAMPLES);
+    Ok(u64::try_from(n)?)
+}
+
+pub struct P2;
+
+common::solution!(P2, Part::Two, |file| Garden::from_str(file)?, |garden| {
+    solve(&garden, STEPS)
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(10 => 50; "walked")]
+    #[test_case(500 => 167_004)]
+    #[test_case(1000 => 668_697)]
+    #[test_case(5000 => 16_733_044)]
+    fn example(steps: usize) -> u64 {
+        let garden = Garden::from_str(include_str!("../inputs/example.txt")).unwrap();
+        solve(&garden, steps).unwrap()
+    }
+}
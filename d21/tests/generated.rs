@@ -0,0 +1 @@
+common::generated_input_tests!(d21::gen::input, d21::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,24 @@
+//! Snapshots of `size` bricks, each up to 4 cubes long, falling over a 10×10 area
+//!
+//! The bricks start out one above the other, so that none of them overlap,
+//! but they're listed in no particular order, like in the puzzle
+
+use common::gen::{Rng, SliceRandom, StdRng};
+
+const AREA: u32 = 10;
+
+pub fn input(rng: &mut StdRng, size: usize) -> String {
+    let mut z = 1;
+    let mut bricks: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let len = rng.gen_range(0..4);
+            let (x, y) = (rng.gen_range(0..AREA - len), rng.gen_range(0..AREA - len));
+            let [dx, dy, dz] = *[[len, 0, 0], [0, len, 0], [0, 0, len]].choose(rng).unwrap();
+            let brick = format!("{x},{y},{z}~{},{},{}", x + dx, y + dy, z + dz);
+            z += dz + rng.gen_range(1..=3);
+            brick
+        })
+        .collect();
+    bricks.shuffle(rng);
+    bricks.join("\n") + "\n"
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use anyhow::ensure;
+use common::Solution;
+
+pub mod gen;
+pub mod p1;
+pub mod p2;
+mod parse;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Point {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+/// The cubes from `from` to `to`, both included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Brick {
+    from: Point,
+    to: Point,
+}
+
+impl Brick {
+    fn new(from: Point, to: Point) -> anyhow::Result<Self> {
+        ensure!(
+            from.x <= to.x && from.y <= to.y && from.z <= to.z,
+            "the brick doesn't go from its lowest coordinates to its highest ones"
+        );
+        ensure!(from.z >= 1, "the brick is in the ground");
+        Ok(Self { from, to })
+    }
+
+    /// The `(x, y)` of the columns the brick takes up
+    fn footprint(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (self.from.x..=self.to.x).flat_map(|x| (self.from.y..=self.to.y).map(move |y| (x, y)))
+    }
+
+    fn height(&self) -> u32 {
+        self.to.z - self.from.z + 1
+    }
+}
+
+/// The bricks, as they are while still falling
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Snapshot(Vec<Brick>);
+
+/// Which bricks rest on which once they've all settled, by their positions in the snapshot
+#[derive(Debug, Default)]
+struct Stack {
+    /// The bricks each of the bricks lies on, none for those on the ground
+    supporters: Vec<Vec<usize>>,
+    /// The bricks lying on each of the bricks
+    supported: Vec<Vec<usize>>,
+}
+
+impl Snapshot {
+    /// Lets the bricks fall, the lowest ones first, each coming to rest on the highest
+    /// of the bricks under it, or on the ground
+    fn settle(&self) -> Stack {
+        let mut order: Vec<usize> = (0..self.0.len()).collect();
+        order.sort_unstable_by_key(|&i| self.0[i].from.z);
+
+        // the height of the top of each column, and the brick at the top
+        let mut heights: HashMap<(u32, u32), (u32, usize)> = HashMap::new();
+        let mut stack = Stack {
+            supporters: vec![vec![]; self.0.len()],
+            supported: vec![vec![]; self.0.len()],
+        };
+        for i in order {
+            let brick = &self.0[i];
+            let top = brick
+                .footprint()
+                .filter_map(|column| heights.get(&column))
+                .map(|&(height, _)| height)
+                .max()
+                .unwrap_or(0);
+
+            let mut supporters: Vec<usize> = brick
+                .footprint()
+                .filter_map(|column| match heights.get(&column) {
+                    Some(&(height, below)) if height == top => Some(below),
+                    _ => None,
+                })
+                .collect();
+            supporters.sort_unstable();
+            supporters.dedup();
+            for &below in &supporters {
+                stack.supported[below].push(i);
+            }
+            stack.supporters[i] = supporters;
+
+            for column in brick.footprint() {
+                heights.insert(column, (top + brick.height(), i));
+            }
+        }
+        stack
+    }
+}
+
+const DAY: u8 = 22;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[&p1::P1, &p2::P2];
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn settles_the_example() {
+        let snapshot = Snapshot::from_str(include_str!("../inputs/example.txt")).unwrap();
+        let stack = snapshot.settle();
+        // A holds up B and C, which both hold up D and E, which both hold up F, which holds up G
+        assert_eq!(
+            stack.supporters,
+            [
+                vec![],
+                vec![0],
+                vec![0],
+                vec![1, 2],
+                vec![1, 2],
+                vec![3, 4],
+                vec![5]
+            ]
+        );
+        assert_eq!(stack.supported[0], [1, 2]);
+        assert!(stack.supported[6].is_empty());
+    }
+}
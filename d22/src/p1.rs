@@ -0,0 +1,31 @@
+use core::str::FromStr;
+
+use common::Part;
+
+use crate::{Snapshot, Stack};
+
+pub fn p1(file: &str) -> anyhow::Result<usize> {
+    Ok(solve(&Snapshot::from_str(file)?))
+}
+
+/// The number of bricks which can be taken out without any other falling,
+/// as each of the bricks on them lies on another one too
+fn solve(snapshot: &Snapshot) -> usize {
+    let Stack {
+        supporters,
+        supported,
+    } = snapshot.settle();
+    supported
+        .iter()
+        .filter(|above| above.iter().all(|&b| supporters[b].len() > 1))
+        .count()
+}
+
+pub struct P1;
+
+common::solution!(
+    P1,
+    Part::One,
+    |file| Snapshot::from_str(file)?,
+    |snapshot| solve(&snapshot)
+);
@@ -0,0 +1,62 @@
+use core::str::FromStr;
+use std::collections::VecDeque;
+
+use common::Part;
+
+use crate::{Snapshot, Stack};
+
+impl Stack {
+    /// The number of other bricks which fall when `brick` is taken out
+    fn n_falling(&self, brick: usize) -> usize {
+        let mut fallen = vec![false; self.supporters.len()];
+        fallen[brick] = true;
+        let mut queue = VecDeque::from([brick]);
+        let mut n_falling = 0;
+        while let Some(b) = queue.pop_front() {
+            for &above in &self.supported[b] {
+                // a brick falls once all of the bricks it lies on have
+                if !fallen[above] && self.supporters[above].iter().all(|&s| fallen[s]) {
+                    fallen[above] = true;
+                    n_falling += 1;
+                    queue.push_back(above);
+                }
+            }
+        }
+        n_falling
+    }
+}
+
+pub fn p2(file: &str) -> anyhow::Result<usize> {
+    Ok(solve(&Snapshot::from_str(file)?))
+}
+
+/// The number of bricks which would fall, summed over taking out each of the bricks
+fn solve(snapshot: &Snapshot) -> usize {
+    let stack = snapshot.settle();
+    (0..stack.supporters.len())
+        .map(|brick| stack.n_falling(brick))
+        .sum()
+}
+
+pub struct P2;
+
+common::solution!(
+    P2,
+    Part::Two,
+    |file| Snapshot::from_str(file)?,
+    |snapshot| solve(&snapshot)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0 => 6; "the bottom one")]
+    #[test_case(1 => 0; "one of two holding up the same")]
+    #[test_case(5 => 1; "the one holding up the top one")]
+    fn n_falling(brick: usize) -> usize {
+        let snapshot = Snapshot::from_str(include_str!("../inputs/example.txt")).unwrap();
+        snapshot.settle().n_falling(brick)
+    }
+}
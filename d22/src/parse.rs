@@ -0,0 +1,63 @@
+use common::{
+    impl_from_str_from_nom_parser,
+    parse::{rule, separated_list1, IResult},
+};
+use nom::{
+    character::complete::{char, newline, u32},
+    combinator::{map, map_res},
+    sequence::{separated_pair, tuple},
+};
+
+use crate::{Brick, Point, Snapshot};
+
+fn point(i: &str) -> IResult<&str, Point> {
+    rule(
+        "point",
+        map(
+            tuple((u32, char(','), u32, char(','), u32)),
+            |(x, _, y, _, z)| Point { x, y, z },
+        ),
+    )(i)
+}
+
+fn brick(i: &str) -> IResult<&str, Brick> {
+    rule(
+        "brick",
+        map_res(separated_pair(point, char('~'), point), |(from, to)| {
+            Brick::new(from, to)
+        }),
+    )(i)
+}
+
+fn snapshot(i: &str) -> IResult<&str, Snapshot> {
+    rule("snapshot", map(separated_list1(newline, brick), Snapshot))(i)
+}
+
+impl_from_str_from_nom_parser!(snapshot, Snapshot);
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn brick() {
+        let snapshot = Snapshot::from_str("1,0,1~1,2,1").unwrap();
+        assert_eq!(
+            snapshot.0,
+            [Brick {
+                from: Point { x: 1, y: 0, z: 1 },
+                to: Point { x: 1, y: 2, z: 1 }
+            }]
+        );
+    }
+
+    #[test_case("1,2,1~1,0,1"; "backwards")]
+    #[test_case("1,0,0~1,2,0"; "in the ground")]
+    #[test_case("1,0~1,2"; "flat")]
+    fn invalid(snapshot: &str) {
+        assert!(Snapshot::from_str(snapshot).is_err());
+    }
+}
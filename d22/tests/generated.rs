@@ -0,0 +1 @@
+common::generated_input_tests!(d22::gen::input, d22::SOLUTIONS, [1, 5, 20]);
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(file) = core::str::from_utf8(data) {
+        let _ = d01::p1::p1(file);
+    }
+});
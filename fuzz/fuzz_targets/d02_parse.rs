@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(file) = core::str::from_utf8(data) {
+        for solution in d02::SOLUTIONS {
+            let _ = solution.parse(file);
+        }
+    }
+});
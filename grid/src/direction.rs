@@ -0,0 +1,179 @@
+//! Directions to move in on a grid, with `Up` being towards the first row
+
+use libaoc::points::Point2D;
+
+use crate::Pos;
+
+/// One of the 4 orthogonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All the directions, clockwise from `Up`
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction a quarter turn clockwise from this one
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    /// The direction a quarter turn counterclockwise from this one
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// One step from `pos` in this direction, or `None` if it would lead past the top or the left edge
+    ///
+    /// Use [`crate::Grid::step`] to also stay within the bottom and the right edge
+    pub fn step(self, pos: Pos) -> Option<Pos> {
+        Direction8::from(self).step(pos)
+    }
+
+    /// `^`, `>`, `v` or `<`
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+/// One of the 4 orthogonal or the 4 diagonal directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All the directions, clockwise from `Up`
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The direction a quarter turn clockwise from this one
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 2) % 8]
+    }
+
+    /// The direction a quarter turn counterclockwise from this one
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 6) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// How much a step in this direction changes `x` and `y`
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Right => (1, 0),
+            Self::DownRight => (1, 1),
+            Self::Down => (0, 1),
+            Self::DownLeft => (-1, 1),
+            Self::Left => (-1, 0),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+
+    /// One step from `pos` in this direction, or `None` if it would lead past the top or the left edge
+    ///
+    /// Use [`crate::Grid::step`] to also stay within the bottom and the right edge
+    pub fn step(self, Point2D(x, y): Pos) -> Option<Pos> {
+        let (dx, dy) = self.offset();
+        Some(Point2D(
+            x.checked_add_signed(dx)?,
+            y.checked_add_signed(dy)?,
+        ))
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::Up,
+            Direction::Right => Self::Right,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Direction::Up => (Direction::Right, Direction::Left, Direction::Down); "up")]
+    #[test_case(Direction::Left => (Direction::Up, Direction::Down, Direction::Right); "left")]
+    fn turns(d: Direction) -> (Direction, Direction, Direction) {
+        (d.turn_right(), d.turn_left(), d.reverse())
+    }
+
+    #[test_case(Direction8::UpRight => (Direction8::DownRight, Direction8::UpLeft, Direction8::DownLeft); "up right")]
+    #[test_case(Direction8::Left => (Direction8::Up, Direction8::Down, Direction8::Right); "left")]
+    fn turns8(d: Direction8) -> (Direction8, Direction8, Direction8) {
+        (d.turn_right(), d.turn_left(), d.reverse())
+    }
+
+    #[test_case(Direction8::Up, Point2D(1, 0) => None; "past the top")]
+    #[test_case(Direction8::DownLeft, Point2D(0, 1) => None; "past the left")]
+    #[test_case(Direction8::DownRight, Point2D(0, 0) => Some(Point2D(1, 1)); "diagonally")]
+    fn step(d: Direction8, pos: Pos) -> Option<Pos> {
+        d.step(pos)
+    }
+}
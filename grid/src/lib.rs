@@ -0,0 +1,300 @@
+//! A rectangular grid of cells, the way many of the puzzles lay out their input
+//!
+//! Positions are `Point2D(x, y)`, with `x` growing to the right and `y` growing downwards,
+//! so `Point2D(0, 0)` is the top-left corner, and the rows are numbered top to bottom
+
+use core::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use common::parse::{self, ParseError};
+use libaoc::points::Point2D;
+
+pub mod direction;
+pub mod picture;
+#[cfg(feature = "serde")]
+pub mod serialize;
+
+pub use direction::{Direction, Direction8};
+
+pub type Pos = Point2D<usize>;
+
+/// A non-empty rectangle of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// The grid made of `rows`, or `None` if there are no cells or the rows aren't all equally long
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Option<Self> {
+        let mut rows = rows.into_iter();
+        let mut cells = rows.next()?;
+        let width = cells.len();
+        if width == 0 {
+            return None;
+        }
+        for row in rows {
+            if row.len() != width {
+                return None;
+            }
+            cells.extend(row);
+        }
+        Some(Self { cells, width })
+    }
+
+    /// Parses the grid of characters `s`, converting each of them with `cell`
+    pub fn parse<E: Display>(
+        s: &str,
+        cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let rows = parse::grid(s, cell)?;
+        // `parse::grid` has already made sure that the grid is a non-empty rectangle
+        Ok(Self::from_rows(rows).expect("a non-empty rectangle"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width
+    }
+
+    fn offset(&self, Point2D(x, y): Pos) -> Option<usize> {
+        (x < self.width && y < self.height()).then_some(y * self.width + x)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.offset(pos).is_some()
+    }
+
+    /// The cell at `pos`, or `None` if `pos` is outside of the grid
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Swaps the cells at `a` and `b`
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        let (a, b) = (self.offset(a), self.offset(b));
+        self.cells.swap(
+            a.expect("`a` is in the grid"),
+            b.expect("`b` is in the grid"),
+        );
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The rows, top to bottom
+    pub fn rows(&self) -> core::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn rows_mut(&mut self) -> core::slice::ChunksMut<'_, T> {
+        self.cells.chunks_mut(self.width)
+    }
+
+    /// The cells of column `x`, top to bottom
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The columns, left to right
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator
+    {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    /// All the positions in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point2D(i % width, i / width))
+    }
+
+    /// All the cells along with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// One step from `pos` in `direction`, or `None` if it would lead outside of the grid
+    pub fn step(&self, pos: Pos, direction: impl Into<Direction8>) -> Option<Pos> {
+        let pos = direction.into().step(pos)?;
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbours of `pos` which are inside of the grid, clockwise from the one above
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` which are inside of the grid,
+    /// clockwise from the one above
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.into_iter().map(f).collect(),
+            width: self.width,
+        }
+    }
+
+    /// Like [`Grid::map`], but also passes the position of each cell to `f`
+    pub fn map_with_positions<U>(&self, mut f: impl FnMut(Pos, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+            width: self.width,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid mirrored along its main diagonal, so that the rows become the columns
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self {
+            cells: self.cols().flatten().cloned().collect(),
+            width: self.height(),
+        }
+    }
+
+    /// The grid turned a quarter clockwise, so that the left column becomes the top row
+    #[must_use]
+    pub fn rotate_clockwise(&self) -> Self {
+        Self {
+            cells: self.cols().flat_map(Iterator::rev).cloned().collect(),
+            width: self.height(),
+        }
+    }
+
+    /// The grid turned a quarter counterclockwise, so that the top row becomes the left column
+    #[must_use]
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self {
+            cells: self.cols().rev().flatten().cloned().collect(),
+            width: self.height(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        let i = self.offset(pos);
+        &self.cells[i.unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let i = self.offset(pos);
+        &mut self.cells[i.unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))]
+    }
+}
+
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, T::try_from)
+    }
+}
+
+/// Shows the cells row by row, each row on its own line
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn shape() {
+        let g = grid("abc\ndef");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Point2D(2, 1)], 'f');
+        assert_eq!(g.get(Point2D(3, 0)), None);
+        assert_eq!(g.row(1), ['d', 'e', 'f']);
+        assert_eq!(g.col(1).collect::<String>(), "be");
+    }
+
+    #[test_case("" => (1, 1); "empty")]
+    #[test_case("ab\nc" => (2, 2); "ragged")]
+    fn invalid(s: &str) -> (usize, usize) {
+        let e = s.parse::<Grid<char>>().unwrap_err();
+        (e.line, e.column)
+    }
+
+    #[test]
+    fn from_rows() {
+        assert_eq!(Grid::<u8>::from_rows([]), None);
+        assert_eq!(Grid::<u8>::from_rows([vec![]]), None);
+        assert_eq!(Grid::from_rows([vec![1, 2], vec![3]]), None);
+        assert_eq!(
+            Grid::from_rows([vec![1, 2], vec![3, 4]]),
+            Some(grid("12\n34").map(|c| c.to_digit(10).unwrap()))
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            g.rotate_clockwise().rotate_clockwise().to_string(),
+            "fed\ncba\n"
+        );
+    }
+
+    #[test_case(Point2D(0, 0) => vec![Point2D(1, 0), Point2D(0, 1)]; "corner")]
+    #[test_case(Point2D(1, 1) => vec![Point2D(1, 0), Point2D(2, 1), Point2D(1, 2), Point2D(0, 1)]; "middle")]
+    #[test_case(Point2D(2, 2) => vec![Point2D(2, 1), Point2D(1, 2)]; "opposite corner")]
+    fn neighbours4(pos: Pos) -> Vec<Pos> {
+        grid("...\n...\n...").neighbours4(pos).collect()
+    }
+
+    #[test_case(Point2D(0, 0) => 3; "corner")]
+    #[test_case(Point2D(1, 0) => 5; "edge")]
+    #[test_case(Point2D(1, 1) => 8; "middle")]
+    fn neighbours8(pos: Pos) -> usize {
+        grid("...\n...\n...").neighbours8(pos).count()
+    }
+}
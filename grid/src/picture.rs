@@ -0,0 +1,87 @@
+//! Pictures of the puzzles' grids, for the runner to show in the terminal or save as images
+//!
+//! A day draws a [`Picture`] of the state it's interested in, tile by tile. How the tiles
+//! end up looking in each of the output formats is up to the runner
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0x10, 0x10, 0x18);
+    pub const GREY: Self = Self(0x60, 0x60, 0x68);
+    pub const WHITE: Self = Self(0xf0, 0xf0, 0xf0);
+    pub const RED: Self = Self(0xe0, 0x40, 0x30);
+    pub const ORANGE: Self = Self(0xf0, 0x90, 0x20);
+    pub const YELLOW: Self = Self(0xf0, 0xe0, 0x40);
+    pub const GREEN: Self = Self(0x40, 0xc0, 0x50);
+    pub const BLUE: Self = Self(0x30, 0x60, 0xd0);
+
+    /// The colour `t` of the way from `self` to `other`, with `t` between 0 and 1
+    #[must_use]
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Self(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    /// The colour as `#rrggbb`
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A glyph drawn in `fg` over `bg`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tile {
+    pub glyph: char,
+    pub fg: Rgb,
+    pub bg: Rgb,
+}
+
+impl Tile {
+    /// `glyph` in `fg` over the default, black, background
+    pub fn new(glyph: char, fg: Rgb) -> Self {
+        Self {
+            glyph,
+            fg,
+            bg: Rgb::BLACK,
+        }
+    }
+
+    #[must_use]
+    pub fn on(self, bg: Rgb) -> Self {
+        Self { bg, ..self }
+    }
+
+    /// Whether there's nothing but the background to draw, which is the case for ` ` and `.`
+    pub fn is_blank(&self) -> bool {
+        matches!(self.glyph, ' ' | '.')
+    }
+}
+
+pub type Picture = Grid<Tile>;
+
+/// A simulation to step through interactively, looking at a picture of each of its states
+pub trait Simulation {
+    /// The keys for the ways to advance the simulation, along with what each of them does
+    ///
+    /// The first one, if there are any, is the default way, for when it doesn't matter which one it is
+    fn moves(&self) -> &'static [(char, &'static str)];
+
+    /// Advances the simulation the way bound to `key`,
+    /// returning `false` if it can't be advanced that way
+    fn advance(&mut self, key: char) -> bool;
+
+    fn picture(&self) -> Picture;
+
+    /// What there is to know about the current state that the picture doesn't show
+    fn status(&self) -> String;
+
+    /// A copy of the current state, to be able to go back to it
+    fn boxed_clone(&self) -> Box<dyn Simulation>;
+}
@@ -0,0 +1,57 @@
+//! Serialization of grids as their rows, and of positions as `[x, y]`
+
+use libaoc::points::Point2D;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Grid, Pos};
+
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+        Self::from_rows(rows)
+            .ok_or_else(|| D::Error::custom("a grid needs non-empty rows of the same length"))
+    }
+}
+
+/// For `#[serde(with = "grid::serialize::pos")]` on a [`Pos`], which can't implement the traits itself
+pub mod pos {
+    use super::{Deserialize, Deserializer, Point2D, Pos, Serialize, Serializer};
+
+    #[allow(clippy::trivially_copy_pass_by_ref)] // serde passes a reference
+    pub fn serialize<S: Serializer>(
+        &Point2D(x, y): &Pos,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        [x, y].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pos, D::Error> {
+        let [x, y] = <[usize; 2]>::deserialize(deserializer)?;
+        Ok(Point2D(x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grids_are_their_rows() {
+        let grid = Grid::from_rows([vec![1, 2], vec![3, 4]]).unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(json, "[[1,2],[3,4]]");
+        assert_eq!(serde_json::from_str::<Grid<u8>>(&json).unwrap(), grid);
+    }
+
+    #[test]
+    fn ragged_rows_arent_a_grid() {
+        assert!(serde_json::from_str::<Grid<u8>>("[[1,2],[3]]").is_err());
+        assert!(serde_json::from_str::<Grid<u8>>("[]").is_err());
+    }
+}